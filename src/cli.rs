@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+use crate::resources::{LoadOptions, SceneSelector};
+use crate::systems::CameraPreset;
+
+const USAGE: &str = "\
+Usage: bevy_gltf_model_and_animation_preview [OPTIONS] [MODEL]
+
+Arguments:
+  [MODEL]                    glTF/GLB file to open on startup
+
+Options:
+      --animation <NAME>     Select the animation with this name
+      --paused               Start with playback paused
+      --scene <INDEX|NAME>   Spawn this scene instead of the default one
+      --camera <PRESET>      Camera view: front, back, left, right, top, bottom, iso
+      --background <COLOR>   Background color as a hex code (#1e1e1e) or name
+  -h, --help                 Print this help";
+
+/// Options passed on the command line
+#[derive(Resource, Debug, Default)]
+pub struct CliArgs {
+    pub model: Option<PathBuf>,
+    pub load_options: LoadOptions,
+    pub camera: Option<CameraPreset>,
+    pub background: Option<Color>,
+}
+
+impl CliArgs {
+    /// Parse the process arguments, printing usage and exiting on `--help` or errors
+    pub fn from_env() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+
+        if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+            println!("{USAGE}");
+            std::process::exit(0);
+        }
+
+        match Self::parse(args) {
+            Ok(cli) => cli,
+            Err(err) => {
+                eprintln!("error: {err}\n\n{USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    fn parse(args: Vec<String>) -> Result<Self, String> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            // Accept both `--flag value` and `--flag=value`
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if arg.starts_with("--") => {
+                    (flag.to_string(), Some(value.to_string()))
+                }
+                _ => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("missing value for {flag}"))
+            };
+
+            match flag.as_str() {
+                "--animation" => cli.load_options.animation = Some(value()?),
+                "--paused" if inline_value.is_some() => {
+                    return Err(format!("option {flag} takes no value"));
+                }
+                "--paused" => cli.load_options.paused = true,
+                "--scene" => {
+                    let scene = value()?;
+                    cli.load_options.scene = Some(match scene.parse() {
+                        Ok(index) => SceneSelector::Index(index),
+                        Err(_) => SceneSelector::Name(scene),
+                    });
+                }
+                "--camera" => {
+                    let preset = value()?;
+                    cli.camera = Some(
+                        CameraPreset::from_name(&preset)
+                            .ok_or_else(|| format!("unknown camera preset \"{preset}\""))?,
+                    );
+                }
+                "--background" => cli.background = Some(parse_color(&value()?)?),
+                _ if flag.starts_with('-') => return Err(format!("unknown option {flag}")),
+                _ if cli.model.is_some() => return Err(format!("unexpected argument {flag}")),
+                _ => {
+                    // Relative paths would otherwise resolve against the assets folder
+                    let path = std::fs::canonicalize(&flag)
+                        .map_err(|err| format!("cannot open {flag}: {err}"))?;
+                    cli.model = Some(path);
+                }
+            }
+        }
+
+        Ok(cli)
+    }
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+    let color = match value.to_ascii_lowercase().as_str() {
+        "black" => Color::BLACK,
+        "white" => Color::WHITE,
+        "gray" | "grey" => Color::srgb(0.5, 0.5, 0.5),
+        hex => Srgba::hex(hex)
+            .map_err(|_| format!("invalid color \"{value}\""))?
+            .into(),
+    };
+    Ok(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        CliArgs::parse(args.iter().map(|arg| arg.to_string()).collect())
+    }
+
+    #[test]
+    fn parses_values_in_both_forms() {
+        let cli = parse(&[
+            "--animation",
+            "Walk",
+            "--scene=2",
+            "--camera=top",
+            "--paused",
+        ])
+        .unwrap();
+        assert_eq!(cli.load_options.animation.as_deref(), Some("Walk"));
+        assert_eq!(cli.load_options.scene, Some(SceneSelector::Index(2)));
+        assert_eq!(cli.camera, Some(CameraPreset::Top));
+        assert!(cli.load_options.paused);
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        assert_eq!(
+            parse(&["--speed", "2"]).unwrap_err(),
+            "unknown option --speed"
+        );
+        assert_eq!(parse(&["-x"]).unwrap_err(), "unknown option -x");
+    }
+
+    #[test]
+    fn rejects_missing_values() {
+        assert_eq!(
+            parse(&["--animation"]).unwrap_err(),
+            "missing value for --animation"
+        );
+        assert_eq!(
+            parse(&["--paused", "--background"]).unwrap_err(),
+            "missing value for --background"
+        );
+    }
+
+    #[test]
+    fn rejects_values_on_flags() {
+        assert_eq!(
+            parse(&["--paused=yes"]).unwrap_err(),
+            "option --paused takes no value"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_camera_presets() {
+        assert_eq!(
+            parse(&["--camera", "under"]).unwrap_err(),
+            "unknown camera preset \"under\""
+        );
+    }
+
+    #[test]
+    fn parses_colors() {
+        assert_eq!(parse_color("Black"), Ok(Color::BLACK));
+        assert_eq!(parse_color("grey"), Ok(Color::srgb(0.5, 0.5, 0.5)));
+        assert_eq!(parse_color("#ff0000"), Ok(Srgba::RED.into()));
+        assert_eq!(parse_color("ff0000"), Ok(Srgba::RED.into()));
+    }
+
+    #[test]
+    fn rejects_invalid_colors() {
+        assert_eq!(
+            parse_color("teal-ish"),
+            Err("invalid color \"teal-ish\"".to_string())
+        );
+        assert_eq!(
+            parse_color("#12345"),
+            Err("invalid color \"#12345\"".to_string())
+        );
+    }
+}
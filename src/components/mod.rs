@@ -1,8 +1,17 @@
 use bevy::prelude::*;
 
+use crate::resources::{DisplayOption, LightField, RigLightKind};
+use crate::systems::CameraPreset;
+
 /// Marker for file dialog
 pub struct GltfModelFile;
 
+/// Marker for the environment file dialog
+pub struct EnvironmentFile;
+
+/// Marker for the lighting rig file dialogs
+pub struct LightRigFile;
+
 /// Marker for the Open Model button
 #[derive(Component)]
 pub struct OpenButton;
@@ -11,6 +20,50 @@ pub struct OpenButton;
 #[derive(Component)]
 pub struct PlayPauseButton;
 
+/// Marker for the animation sort order button
+#[derive(Component)]
+pub struct SortOrderButton;
+
+/// Marker for the crossfade duration button
+#[derive(Component)]
+pub struct CrossfadeButton;
+
+/// Marker for the timeline track that can be clicked and dragged to scrub
+#[derive(Component)]
+pub struct TimelineBar;
+
+/// Marker for the filled portion of the timeline track
+#[derive(Component)]
+pub struct TimelineFill;
+
+/// Marker for the timeline time and frame readout
+#[derive(Component)]
+pub struct TimelineLabel;
+
+/// Button that steps the active clip by the given number of frames
+#[derive(Component)]
+pub struct FrameStepButton(pub i32);
+
+/// Marker for the timeline frame rate button
+#[derive(Component)]
+pub struct FrameRateButton;
+
+/// Button that changes playback speed by the given number of steps
+#[derive(Component)]
+pub struct SpeedButton(pub i32);
+
+/// Marker for the playback speed readout
+#[derive(Component)]
+pub struct SpeedLabel;
+
+/// Marker for the forward/reverse toggle button
+#[derive(Component)]
+pub struct ReverseButton;
+
+/// Marker for the loop mode button
+#[derive(Component)]
+pub struct LoopModeButton;
+
 /// Marker for the animation label text
 #[derive(Component)]
 pub struct AnimationLabel;
@@ -27,6 +80,14 @@ pub struct AnimationListContainer;
 #[derive(Component)]
 pub struct AnimationListItem(pub usize);
 
+/// Marker for the scene list container
+#[derive(Component)]
+pub struct SceneListContainer;
+
+/// Marker for scene list items with glTF scene index
+#[derive(Component)]
+pub struct SceneListItem(pub usize);
+
 /// Marker for "No animations" text
 #[derive(Component)]
 pub struct NoAnimationsText;
@@ -39,10 +100,278 @@ pub struct DraggablePanel;
 #[derive(Component)]
 pub struct PanelDragArea;
 
-/// Marker for the animation scroll area
+/// Marker for scrollable areas inside panels
 #[derive(Component)]
-pub struct AnimationScrollArea;
+pub struct ScrollArea;
 
 /// Marker for entities with animations loaded
 #[derive(Component)]
 pub struct AnimationsLoaded;
+
+/// Marker for the outliner tree container
+#[derive(Component)]
+pub struct OutlinerContainer;
+
+/// Outliner row for the given scene entity
+#[derive(Component)]
+pub struct OutlinerRow(pub Entity);
+
+/// Button that expands or collapses the given scene entity in the outliner
+#[derive(Component)]
+pub struct OutlinerExpandButton(pub Entity);
+
+/// Button that toggles visibility of the given scene entity
+#[derive(Component)]
+pub struct VisibilityToggle(pub Entity);
+
+/// Marker for the inspector text block
+#[derive(Component)]
+pub struct InspectorText;
+
+/// Marker for the statistics text block
+#[derive(Component)]
+pub struct StatsText;
+
+/// Marker for the "Frame model" button
+#[derive(Component)]
+pub struct FrameModelButton;
+
+/// Marker for camera view preset buttons
+#[derive(Component)]
+pub struct ViewPresetButton(pub CameraPreset);
+
+/// Marker for the perspective/orthographic toggle button
+#[derive(Component)]
+pub struct ProjectionButton;
+
+/// Marker for the button opening the camera dropdown
+#[derive(Component)]
+pub struct CameraDropdownButton;
+
+/// Marker for the camera dropdown list
+#[derive(Component)]
+pub struct CameraDropdownList;
+
+/// Marker for camera dropdown entries, `None` being the orbit camera
+#[derive(Component)]
+pub struct CameraListItem(pub Option<Entity>);
+
+/// Marker for model roots the camera has been framed to
+#[derive(Component)]
+pub struct CameraFramed;
+
+/// Horizontal slider track; `value` is in 0..=1 and follows the cursor while pressed
+#[derive(Component, Default)]
+pub struct Slider {
+    pub value: f32,
+}
+
+/// Marker for the fill bar inside a slider track
+#[derive(Component)]
+pub struct SliderFill;
+
+/// Marker for the environment name text
+#[derive(Component)]
+pub struct EnvironmentLabel;
+
+/// Marker for the button loading an environment map from disk
+#[derive(Component)]
+pub struct EnvironmentLoadButton;
+
+/// Marker for the button restoring the built-in environment
+#[derive(Component)]
+pub struct EnvironmentDefaultButton;
+
+/// Marker for the skybox toggle button
+#[derive(Component)]
+pub struct SkyboxButton;
+
+/// Marker for the environment intensity slider
+#[derive(Component)]
+pub struct EnvironmentIntensitySlider;
+
+/// Marker for the environment intensity readout
+#[derive(Component)]
+pub struct EnvironmentIntensityLabel;
+
+/// Marker for the environment rotation slider
+#[derive(Component)]
+pub struct EnvironmentRotationSlider;
+
+/// Marker for the environment rotation readout
+#[derive(Component)]
+pub struct EnvironmentRotationLabel;
+
+/// Marker for lights spawned from the lighting rig, with their index in the rig
+#[derive(Component)]
+pub struct RigLightEntity(pub usize);
+
+/// Marker for the lighting rig name text
+#[derive(Component)]
+pub struct LightRigLabel;
+
+/// Marker for the button loading a lighting rig
+#[derive(Component)]
+pub struct LightRigLoadButton;
+
+/// Marker for the button saving the lighting rig
+#[derive(Component)]
+pub struct LightRigSaveButton;
+
+/// Marker for the button restoring the default lighting rig
+#[derive(Component)]
+pub struct LightRigResetButton;
+
+/// Marker for the rig light list container
+#[derive(Component)]
+pub struct LightListContainer;
+
+/// Marker for rig light list items with index
+#[derive(Component)]
+pub struct LightListItem(pub usize);
+
+/// Marker for buttons adding a light of the given kind
+#[derive(Component)]
+pub struct AddLightButton(pub RigLightKind);
+
+/// Marker for the button removing the selected light
+#[derive(Component)]
+pub struct RemoveLightButton;
+
+/// Marker for the shadow toggle of the selected light
+#[derive(Component)]
+pub struct LightShadowsButton;
+
+/// Marker for the shadow cascade count button
+#[derive(Component)]
+pub struct CascadesButton;
+
+/// Marker for sliders editing a lighting rig value
+#[derive(Component)]
+pub struct LightSlider(pub LightField);
+
+/// Marker for the readout of a lighting rig value
+#[derive(Component)]
+pub struct LightFieldLabel(pub LightField);
+
+/// Marker for the shadow-catcher ground plane placed under the model
+#[derive(Component)]
+pub struct GroundPlane;
+
+/// Marker for buttons toggling a display setting
+#[derive(Component)]
+pub struct DisplayToggle(pub DisplayOption);
+
+/// Marker for the name of the skeleton joint under the cursor
+#[derive(Component)]
+pub struct JointLabel;
+
+/// Marker for the button cycling the shading mode
+#[derive(Component)]
+pub struct ShadingButton;
+
+/// Mesh an entity had before a debug mode replaced it
+#[derive(Component)]
+pub struct OriginalMesh(pub Handle<Mesh>);
+
+/// Marker for the button cycling the material channel view
+#[derive(Component)]
+pub struct MaterialViewButton;
+
+/// Material an entity had before a debug view replaced it
+#[derive(Component)]
+pub struct OriginalMaterial(pub Handle<StandardMaterial>);
+
+/// Marker for the material count heading
+#[derive(Component)]
+pub struct MaterialCountLabel;
+
+/// Marker for the material list container
+#[derive(Component)]
+pub struct MaterialListContainer;
+
+/// Marker for material list items with index
+#[derive(Component)]
+pub struct MaterialListItem(pub usize);
+
+/// Marker for the texture count heading
+#[derive(Component)]
+pub struct TextureCountLabel;
+
+/// Marker for the texture list container
+#[derive(Component)]
+pub struct TextureListContainer;
+
+/// Marker for texture list rows
+#[derive(Component)]
+pub struct TextureListItem;
+
+/// Marker for the material variant count heading
+#[derive(Component)]
+pub struct VariantCountLabel;
+
+/// Marker for the material variant list container
+#[derive(Component)]
+pub struct VariantListContainer;
+
+/// Marker for material variant list items, `None` for the authored materials
+#[derive(Component)]
+pub struct VariantListItem(pub Option<usize>);
+
+/// Marker for the morph target summary text
+#[derive(Component)]
+pub struct MorphSummaryLabel;
+
+/// Marker for the button letting animations override the morph target sliders
+#[derive(Component)]
+pub struct MorphOverrideButton;
+
+/// Marker for the morph target list container
+#[derive(Component)]
+pub struct MorphListContainer;
+
+/// Marker for rows of the morph target list
+#[derive(Component)]
+pub struct MorphListItem;
+
+/// Slider driving one weight of the `MorphWeights` on `entity`
+#[derive(Component)]
+pub struct MorphTargetSlider {
+    pub entity: Entity,
+    pub index: usize,
+    /// Set once the user drags the slider, until then the weight is left to the model
+    pub dragged: bool,
+}
+
+/// Marker for the readout above a morph target slider, with the target name
+#[derive(Component)]
+pub struct MorphTargetLabel(pub String);
+
+/// Viewport readout of a bounding box dimension
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DimensionLabel {
+    /// Model width along X
+    Width,
+    /// Model height along Y
+    Height,
+    /// Model depth along Z
+    Depth,
+    /// All three dimensions of the selected node
+    Selection,
+}
+
+/// Marker for the button toggling measure mode
+#[derive(Component)]
+pub struct MeasureButton;
+
+/// Marker for the vertex snapping toggle of the measure tool
+#[derive(Component)]
+pub struct MeasureSnapButton;
+
+/// Marker for the button removing all measurements
+#[derive(Component)]
+pub struct ClearMeasurementsButton;
+
+/// Viewport readout of a measured distance, with the measurement index
+#[derive(Component)]
+pub struct MeasurementLabel(pub usize);
@@ -1,26 +1,56 @@
+mod cli;
 mod components;
 mod resources;
 mod systems;
 mod ui;
 
-use bevy::{asset::UnapprovedPathMode, prelude::*, winit::WinitWindows};
+use bevy::{
+    app::AnimationSystems, asset::UnapprovedPathMode, mesh::InheritWeightSystems,
+    pbr::wireframe::WireframePlugin, prelude::*, winit::WinitWindows,
+};
 use bevy_file_dialog::prelude::*;
 use bevy_panorbit_camera::PanOrbitCameraPlugin;
 use std::io::Cursor;
 use winit::window::Icon;
 
-use components::GltfModelFile;
-use resources::{ModelViewer, PanelDragState};
+use cli::CliArgs;
+use components::{EnvironmentFile, GltfModelFile, LightRigFile};
+use resources::{
+    ActiveCamera, DisplaySettings, EnvironmentSettings, LightRig, MaterialBrowser,
+    MaterialVariants, Measurements, ModelStats, ModelViewer, MorphTargets, OutlinerState,
+    PanelDragState, Selection,
+};
 use systems::{
-    control_animations, disable_camera_on_ui_hover, drag_panel, handle_loaded_model,
-    scroll_animation_list, setup_animations,
+    OverlayGizmos, ShadowCatcherPlugin, SkeletonGizmos, apply_cli_args, apply_environment,
+    apply_light_rig, apply_material_variant, apply_material_view, apply_shading_mode,
+    apply_wireframe, camera_shortcuts, collect_materials, collect_morph_targets, collect_variants,
+    compute_model_stats, control_animations, disable_camera_on_ui_hover, display_shortcuts,
+    drag_light_handles, drag_panel, draw_axis_gizmo, draw_bounds, draw_grid, draw_light_gizmos,
+    draw_material_highlight, draw_measurements, draw_selection_highlight, draw_skeleton,
+    draw_vertex_vectors, frame_model, handle_environment_file, handle_light_rig_files,
+    handle_loaded_model, measure_shortcuts, pick_mesh, playback_shortcuts, prepare_environment,
+    scroll_panels, setup_animations, setup_default_environment, setup_guides,
+    setup_skeleton_gizmos, spawn_model_scene, sync_active_camera, sync_morph_weights,
+    update_ground_plane, update_measurement_labels,
 };
 use ui::{
-    animation_list_interactions, button_interactions, setup_scene, setup_ui, update_animation_list,
-    update_ui_labels,
+    animation_list_interactions, button_interactions, camera_dropdown_interactions,
+    camera_interactions, display_interactions, drag_sliders, environment_interactions,
+    light_list_interactions, lighting_interactions, material_list_interactions,
+    measure_interactions, morph_interactions, outliner_interactions, playback_interactions,
+    scene_list_interactions, setup_display_panel, setup_environment_panel, setup_inspector,
+    setup_lighting_panel, setup_material_panel, setup_morph_panel, setup_outliner, setup_scene,
+    setup_stats_panel, setup_ui, timeline_interactions, update_animation_list, update_camera_list,
+    update_display_panel, update_environment_panel, update_inspector, update_lighting_panel,
+    update_material_panel, update_measure_buttons, update_morph_labels, update_morph_panel,
+    update_outliner, update_projection_label, update_scene_list, update_slider_fills,
+    update_stats_panel, update_timeline, update_ui_labels, update_variant_list,
+    variant_list_interactions,
 };
 
 fn main() {
+    let cli = CliArgs::from_env();
+
     App::new()
         .add_plugins(
             DefaultPlugins
@@ -39,26 +69,189 @@ fn main() {
                     ..default()
                 }),
         )
-        .add_plugins(PanOrbitCameraPlugin)
-        .add_plugins(FileDialogPlugin::new().with_load_file::<GltfModelFile>())
+        .add_plugins((PanOrbitCameraPlugin, ShadowCatcherPlugin))
+        // Not part of DefaultPlugins
+        .add_plugins((WireframePlugin::default(), MeshPickingPlugin))
+        .add_plugins(
+            FileDialogPlugin::new()
+                .with_load_file::<GltfModelFile>()
+                .with_load_file::<EnvironmentFile>()
+                .with_load_file::<LightRigFile>()
+                .with_save_file::<LightRigFile>(),
+        )
         .init_resource::<ModelViewer>()
         .init_resource::<PanelDragState>()
-        .add_systems(Startup, (setup_scene, setup_ui, set_window_icon))
+        .init_resource::<OutlinerState>()
+        .init_resource::<Selection>()
+        .init_resource::<ModelStats>()
+        .init_resource::<ActiveCamera>()
+        .init_resource::<EnvironmentSettings>()
+        .init_resource::<LightRig>()
+        .init_resource::<DisplaySettings>()
+        .init_resource::<MaterialBrowser>()
+        .init_resource::<MaterialVariants>()
+        .init_resource::<MorphTargets>()
+        .init_resource::<Measurements>()
+        .init_gizmo_group::<OverlayGizmos>()
+        .init_gizmo_group::<SkeletonGizmos>()
+        .insert_resource(cli)
+        .add_systems(
+            Startup,
+            (
+                setup_scene,
+                setup_ui,
+                setup_outliner,
+                setup_inspector,
+                setup_stats_panel,
+                setup_environment_panel,
+                setup_lighting_panel,
+                setup_display_panel,
+                setup_material_panel,
+                setup_morph_panel,
+                setup_guides,
+                setup_skeleton_gizmos,
+                setup_default_environment,
+                set_window_icon,
+                apply_cli_args.after(setup_scene),
+            ),
+        )
         .add_systems(
             Update,
             (
                 handle_loaded_model,
+                spawn_model_scene,
                 setup_animations,
                 control_animations,
+                playback_shortcuts,
                 button_interactions,
                 animation_list_interactions,
+                timeline_interactions,
+                playback_interactions,
                 update_ui_labels,
+                update_timeline,
                 update_animation_list,
                 drag_panel,
                 disable_camera_on_ui_hover,
-                scroll_animation_list,
+                scroll_panels,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                update_scene_list,
+                scene_list_interactions,
+                update_outliner,
+                outliner_interactions,
+                update_inspector,
+            ),
+        )
+        .add_systems(Update, (compute_model_stats, update_stats_panel).chain())
+        .add_systems(
+            Update,
+            (
+                frame_model,
+                camera_shortcuts,
+                camera_interactions,
+                update_projection_label,
+                camera_dropdown_interactions,
+                update_camera_list,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                handle_environment_file,
+                prepare_environment,
+                apply_environment,
+                environment_interactions,
+                update_environment_panel,
+                drag_sliders,
+                update_slider_fills,
             ),
         )
+        .add_systems(
+            Update,
+            (
+                apply_light_rig,
+                draw_light_gizmos,
+                // After the hover check, so a handle drag keeps the orbit camera still
+                drag_light_handles.after(disable_camera_on_ui_hover),
+                handle_light_rig_files,
+                lighting_interactions,
+                light_list_interactions,
+                update_lighting_panel,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                display_shortcuts,
+                draw_grid,
+                draw_axis_gizmo,
+                update_ground_plane,
+                display_interactions,
+                update_display_panel,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                apply_wireframe,
+                apply_shading_mode,
+                draw_vertex_vectors,
+                apply_material_view,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (collect_materials, update_material_panel).chain(),
+                material_list_interactions,
+                draw_material_highlight,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (collect_variants, update_variant_list).chain(),
+                variant_list_interactions,
+                // Before the debug views, so they are built from the variant's materials
+                apply_material_variant.before(apply_material_view),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (collect_morph_targets, update_morph_panel).chain(),
+                morph_interactions,
+                update_morph_labels,
+            ),
+        )
+        .add_systems(Update, (pick_mesh, draw_selection_highlight))
+        .add_systems(
+            Update,
+            (
+                measure_shortcuts,
+                measure_interactions,
+                update_measure_buttons,
+                update_measurement_labels,
+                draw_measurements,
+            ),
+        )
+        // After scene spawning, so glTF cameras never render a frame on their own
+        .add_systems(PostUpdate, sync_active_camera)
+        // After transform propagation, so bones and boxes match the pose being rendered
+        .add_systems(
+            PostUpdate,
+            (draw_skeleton, draw_bounds).after(TransformSystems::Propagate),
+        )
+        // Between the animation writing weights and meshes inheriting them
+        .add_systems(
+            PostUpdate,
+            sync_morph_weights
+                .after(AnimationSystems)
+                .before(InheritWeightSystems),
+        )
         .run();
 }
 
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Resource for the glTF camera the view is rendered from
+#[derive(Resource, Default)]
+pub struct ActiveCamera {
+    /// Camera from the loaded scene, or `None` for the orbit camera
+    pub scene_camera: Option<Entity>,
+}
+
+impl ActiveCamera {
+    /// Step through the orbit camera followed by each scene camera
+    pub fn cycle(&mut self, scene_cameras: &[Entity]) {
+        let next = match self.scene_camera {
+            None => 0,
+            Some(current) => scene_cameras
+                .iter()
+                .position(|entity| *entity == current)
+                .map_or(0, |index| index + 1),
+        };
+        self.scene_camera = scene_cameras.get(next).copied();
+    }
+}
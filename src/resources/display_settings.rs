@@ -0,0 +1,215 @@
+use bevy::pbr::UvChannel;
+use bevy::prelude::*;
+
+/// Resource for the viewport overlays and helpers that can be toggled
+#[derive(Resource)]
+pub struct DisplaySettings {
+    /// Grid on the ground, following the camera
+    pub grid: bool,
+    /// Matte plane under the model that catches its shadows
+    pub ground_plane: bool,
+    /// XYZ orientation gizmo in the corner of the viewport
+    pub axis_gizmo: bool,
+    /// World-space bounding boxes of the model and the selection, with their size
+    pub bounds: bool,
+    /// Bones and joints of skinned meshes
+    pub skeleton: bool,
+    /// Limit the skeleton to the joints above and below the selected node
+    pub skeleton_chain_only: bool,
+    /// Wireframe drawn over every mesh
+    pub wireframe: bool,
+    /// Vertex normals drawn as lines
+    pub normals: bool,
+    /// Vertex tangents drawn as lines
+    pub tangents: bool,
+    pub shading: ShadingMode,
+    /// Material channel shown instead of regular lighting
+    pub material_view: MaterialView,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            grid: true,
+            ground_plane: false,
+            axis_gizmo: true,
+            bounds: false,
+            skeleton: false,
+            skeleton_chain_only: false,
+            wireframe: false,
+            normals: false,
+            tangents: false,
+            shading: ShadingMode::Authored,
+            material_view: MaterialView::Lit,
+        }
+    }
+}
+
+impl DisplaySettings {
+    pub fn is_enabled(&self, option: DisplayOption) -> bool {
+        match option {
+            DisplayOption::Grid => self.grid,
+            DisplayOption::GroundPlane => self.ground_plane,
+            DisplayOption::AxisGizmo => self.axis_gizmo,
+            DisplayOption::Bounds => self.bounds,
+            DisplayOption::Skeleton => self.skeleton,
+            DisplayOption::SkeletonChainOnly => self.skeleton_chain_only,
+            DisplayOption::Wireframe => self.wireframe,
+            DisplayOption::Normals => self.normals,
+            DisplayOption::Tangents => self.tangents,
+        }
+    }
+
+    pub fn toggle(&mut self, option: DisplayOption) {
+        let enabled = match option {
+            DisplayOption::Grid => &mut self.grid,
+            DisplayOption::GroundPlane => &mut self.ground_plane,
+            DisplayOption::AxisGizmo => &mut self.axis_gizmo,
+            DisplayOption::Bounds => &mut self.bounds,
+            DisplayOption::Skeleton => &mut self.skeleton,
+            DisplayOption::SkeletonChainOnly => &mut self.skeleton_chain_only,
+            DisplayOption::Wireframe => &mut self.wireframe,
+            DisplayOption::Normals => &mut self.normals,
+            DisplayOption::Tangents => &mut self.tangents,
+        };
+        *enabled = !*enabled;
+    }
+}
+
+/// A display setting with an on/off button in the Display panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayOption {
+    Grid,
+    GroundPlane,
+    AxisGizmo,
+    Bounds,
+    Skeleton,
+    SkeletonChainOnly,
+    Wireframe,
+    Normals,
+    Tangents,
+}
+
+impl DisplayOption {
+    pub const ALL: [Self; 9] = [
+        Self::Grid,
+        Self::GroundPlane,
+        Self::AxisGizmo,
+        Self::Bounds,
+        Self::Skeleton,
+        Self::SkeletonChainOnly,
+        Self::Wireframe,
+        Self::Normals,
+        Self::Tangents,
+    ];
+
+    /// Button label without the on/off state
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Grid => "Grid",
+            Self::GroundPlane => "Ground",
+            Self::AxisGizmo => "Axes",
+            Self::Bounds => "Bounds",
+            Self::Skeleton => "Bones",
+            Self::SkeletonChainOnly => "Selected chain",
+            Self::Wireframe => "Wireframe",
+            Self::Normals => "Normals",
+            Self::Tangents => "Tangents",
+        }
+    }
+}
+
+/// How the normals of the loaded meshes are shaded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShadingMode {
+    /// Normals as exported
+    #[default]
+    Authored,
+    /// One normal per triangle, showing the actual faces
+    Flat,
+    /// Normals recomputed by averaging the faces around shared vertices
+    Smooth,
+}
+
+impl ShadingMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Authored => Self::Flat,
+            Self::Flat => Self::Smooth,
+            Self::Smooth => Self::Authored,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Authored => "Authored",
+            Self::Flat => "Flat",
+            Self::Smooth => "Smooth",
+        }
+    }
+}
+
+/// Material channel the model is rendered with, for checking textures in isolation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MaterialView {
+    /// Regular shading with the authored materials
+    #[default]
+    Lit,
+    BaseColor,
+    Metallic,
+    Roughness,
+    NormalMap,
+    Occlusion,
+    Emissive,
+    /// Texture coordinates as red and green
+    Uv0,
+    Uv1,
+    /// Lit checker texture on the first UV set, for spotting stretching and seams
+    CheckerUv0,
+    CheckerUv1,
+}
+
+impl MaterialView {
+    pub const ALL: [Self; 11] = [
+        Self::Lit,
+        Self::BaseColor,
+        Self::Metallic,
+        Self::Roughness,
+        Self::NormalMap,
+        Self::Occlusion,
+        Self::Emissive,
+        Self::Uv0,
+        Self::Uv1,
+        Self::CheckerUv0,
+        Self::CheckerUv1,
+    ];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|view| *view == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Lit => "Lit",
+            Self::BaseColor => "Base color",
+            Self::Metallic => "Metallic",
+            Self::Roughness => "Roughness",
+            Self::NormalMap => "Normal map",
+            Self::Occlusion => "Occlusion",
+            Self::Emissive => "Emissive",
+            Self::Uv0 => "UV0",
+            Self::Uv1 => "UV1",
+            Self::CheckerUv0 => "Checker UV0",
+            Self::CheckerUv1 => "Checker UV1",
+        }
+    }
+
+    /// Texture coordinates the UV views sample with
+    pub fn uv_channel(self) -> UvChannel {
+        match self {
+            Self::Uv1 | Self::CheckerUv1 => UvChannel::Uv1,
+            _ => UvChannel::Uv0,
+        }
+    }
+}
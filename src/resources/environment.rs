@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy::tasks::Task;
+
+/// Upper end of the intensity slider, in cd/m²
+pub const MAX_ENVIRONMENT_INTENSITY: f32 = 4000.0;
+
+/// Resource for the image-based lighting environment
+#[derive(Resource)]
+pub struct EnvironmentSettings {
+    /// Name shown in the environment panel
+    pub name: String,
+    /// Cubemap used for lighting and the skybox
+    pub cubemap: Option<Handle<Image>>,
+    /// Image still loading from disk, with its file name
+    pub pending: Option<(Handle<Image>, String)>,
+    /// Cubemap being built from the pending panorama in the background
+    pub conversion: Option<Task<Image>>,
+    /// Brightness of the lighting and skybox in cd/m²
+    pub intensity: f32,
+    /// Rotation around the vertical axis in degrees
+    pub rotation: f32,
+    pub show_skybox: bool,
+}
+
+impl Default for EnvironmentSettings {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            cubemap: None,
+            pending: None,
+            conversion: None,
+            intensity: 1000.0,
+            rotation: 0.0,
+            show_skybox: true,
+        }
+    }
+}
+
+impl EnvironmentSettings {
+    pub fn rotation_quat(&self) -> Quat {
+        Quat::from_rotation_y(self.rotation.to_radians())
+    }
+}
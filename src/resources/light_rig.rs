@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+use std::fmt::Write;
+
+/// Kinds of light a rig can contain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RigLightKind {
+    Directional,
+    Point,
+    Spot,
+}
+
+impl RigLightKind {
+    pub const ALL: [Self; 3] = [Self::Directional, Self::Point, Self::Spot];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Directional => "directional",
+            Self::Point => "point",
+            Self::Spot => "spot",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Directional => "Directional",
+            Self::Point => "Point",
+            Self::Spot => "Spot",
+        }
+    }
+
+    /// Upper end of the intensity slider, in lux for directional lights and lumens otherwise
+    pub fn max_intensity(self) -> f32 {
+        match self {
+            Self::Directional => 100_000.0,
+            Self::Point | Self::Spot => 4_000_000.0,
+        }
+    }
+
+    pub fn intensity_unit(self) -> &'static str {
+        match self {
+            Self::Directional => "lx",
+            Self::Point | Self::Spot => "lm",
+        }
+    }
+}
+
+/// One light of a rig, positioned on a sphere around the origin and pointing at it
+#[derive(Clone, Debug, PartialEq)]
+pub struct RigLight {
+    pub kind: RigLightKind,
+    pub color: Srgba,
+    /// Lux for directional lights, lumens for point and spot lights
+    pub intensity: f32,
+    /// Angle around the vertical axis in degrees
+    pub yaw: f32,
+    /// Elevation above the horizon in degrees
+    pub pitch: f32,
+    /// Distance from the origin; directional lights only use it for the gizmo
+    pub distance: f32,
+    pub shadows: bool,
+}
+
+impl RigLight {
+    pub fn new(kind: RigLightKind) -> Self {
+        Self {
+            kind,
+            color: Srgba::WHITE,
+            intensity: match kind {
+                RigLightKind::Directional => 10_000.0,
+                RigLightKind::Point | RigLightKind::Spot => 1_000_000.0,
+            },
+            yaw: 45.0,
+            pitch: 45.0,
+            distance: 5.0,
+            shadows: false,
+        }
+    }
+
+    pub fn position(&self) -> Vec3 {
+        let (yaw, pitch) = (self.yaw.to_radians(), self.pitch.to_radians());
+        Vec3::new(
+            pitch.cos() * yaw.sin(),
+            pitch.sin(),
+            pitch.cos() * yaw.cos(),
+        ) * self.distance
+    }
+
+    pub fn transform(&self) -> Transform {
+        // Looking straight up or down has no unique up vector, so fall back to Z
+        let up = if self.pitch.abs() > 89.0 {
+            Vec3::Z
+        } else {
+            Vec3::Y
+        };
+        Transform::from_translation(self.position()).looking_at(Vec3::ZERO, up)
+    }
+}
+
+/// Resource for the editable set of scene lights
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct LightRig {
+    pub name: String,
+    pub lights: Vec<RigLight>,
+    /// Index of the light edited in the panel
+    pub selected: usize,
+    /// Shadow cascades used by directional lights
+    pub cascades: usize,
+    /// Distance covered by directional light shadows
+    pub shadow_distance: f32,
+}
+
+impl Default for LightRig {
+    /// The sun light the viewer always had, coming from (5, 10, 5)
+    fn default() -> Self {
+        Self {
+            name: "Default".to_string(),
+            lights: vec![RigLight {
+                yaw: 45.0,
+                pitch: 54.7,
+                distance: 12.2,
+                shadows: true,
+                ..RigLight::new(RigLightKind::Directional)
+            }],
+            selected: 0,
+            cascades: 4,
+            shadow_distance: 150.0,
+        }
+    }
+}
+
+impl LightRig {
+    pub fn selected_light(&self) -> Option<&RigLight> {
+        self.lights.get(self.selected)
+    }
+
+    pub fn selected_light_mut(&mut self) -> Option<&mut RigLight> {
+        self.lights.get_mut(self.selected)
+    }
+
+    /// Serialize to the line-based `.rig` format read by [`LightRig::parse`]
+    pub fn to_text(&self) -> String {
+        // Writing to a String cannot fail, so the results are ignored
+        let mut out = String::from("# Lighting rig\n");
+        let _ = writeln!(
+            out,
+            "shadows cascades={} distance={}",
+            self.cascades, self.shadow_distance
+        );
+        for light in &self.lights {
+            let _ = writeln!(
+                out,
+                "{} color={} intensity={} yaw={} pitch={} distance={} shadows={}",
+                light.kind.name(),
+                light.color.to_hex(),
+                light.intensity,
+                light.yaw,
+                light.pitch,
+                light.distance,
+                if light.shadows { "on" } else { "off" }
+            );
+        }
+        out
+    }
+
+    /// Read a rig written by [`LightRig::to_text`]
+    pub fn parse(name: &str, text: &str) -> Result<Self, String> {
+        let mut rig = Self {
+            name: name.to_string(),
+            lights: Vec::new(),
+            ..default()
+        };
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| format!("line {}: {message}", line_number + 1);
+
+            let mut words = line.split_whitespace();
+            let kind = words.next().unwrap_or_default();
+            let mut light = RigLightKind::ALL
+                .into_iter()
+                .find(|candidate| candidate.name() == kind)
+                .map(RigLight::new);
+            if light.is_none() && kind != "shadows" {
+                return Err(error(format!("unknown entry \"{kind}\"")));
+            }
+
+            for word in words {
+                let (key, value) = word
+                    .split_once('=')
+                    .ok_or_else(|| error(format!("expected key=value, got \"{word}\"")))?;
+                let number = || {
+                    value
+                        .parse::<f32>()
+                        .map_err(|_| error(format!("invalid number \"{value}\"")))
+                };
+                match (&mut light, key) {
+                    (None, "cascades") => {
+                        rig.cascades = value
+                            .parse::<usize>()
+                            .map_err(|_| error(format!("invalid cascade count \"{value}\"")))?
+                            .clamp(1, 4);
+                    }
+                    (None, "distance") => rig.shadow_distance = number()?,
+                    (Some(light), "color") => {
+                        light.color = Srgba::hex(value)
+                            .map_err(|_| error(format!("invalid color \"{value}\"")))?;
+                    }
+                    (Some(light), "intensity") => light.intensity = number()?,
+                    (Some(light), "yaw") => light.yaw = number()?,
+                    (Some(light), "pitch") => light.pitch = number()?,
+                    (Some(light), "distance") => light.distance = number()?,
+                    (Some(light), "shadows") => light.shadows = value == "on",
+                    _ => return Err(error(format!("unknown setting \"{key}\""))),
+                }
+            }
+
+            rig.lights.extend(light);
+        }
+
+        Ok(rig)
+    }
+}
+
+/// Rig values edited with sliders in the lighting panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightField {
+    Red,
+    Green,
+    Blue,
+    Intensity,
+    Yaw,
+    Pitch,
+    Distance,
+    ShadowDistance,
+}
+
+impl LightField {
+    pub const ALL: [Self; 8] = [
+        Self::Red,
+        Self::Green,
+        Self::Blue,
+        Self::Intensity,
+        Self::Yaw,
+        Self::Pitch,
+        Self::Distance,
+        Self::ShadowDistance,
+    ];
+
+    /// Slider position in 0..=1, or `None` when the rig has no light to edit
+    pub fn slider_value(self, rig: &LightRig) -> Option<f32> {
+        let value = match (self, rig.selected_light()) {
+            (Self::ShadowDistance, _) => (rig.shadow_distance - 2.0) / 498.0,
+            (_, None) => return None,
+            (Self::Red, Some(light)) => light.color.red,
+            (Self::Green, Some(light)) => light.color.green,
+            (Self::Blue, Some(light)) => light.color.blue,
+            // Square root spreads the low end, where most useful values are
+            (Self::Intensity, Some(light)) => (light.intensity / light.kind.max_intensity()).sqrt(),
+            (Self::Yaw, Some(light)) => light.yaw.rem_euclid(360.0) / 360.0,
+            (Self::Pitch, Some(light)) => (light.pitch + 90.0) / 180.0,
+            (Self::Distance, Some(light)) => (light.distance - 0.5) / 49.5,
+        };
+        Some(value.clamp(0.0, 1.0))
+    }
+
+    /// Inverse of [`LightField::slider_value`]
+    pub fn set_slider_value(self, rig: &mut LightRig, value: f32) {
+        match (self, rig.lights.get_mut(rig.selected)) {
+            (Self::ShadowDistance, _) => rig.shadow_distance = 2.0 + value * 498.0,
+            (_, None) => {}
+            (Self::Red, Some(light)) => light.color.red = value,
+            (Self::Green, Some(light)) => light.color.green = value,
+            (Self::Blue, Some(light)) => light.color.blue = value,
+            (Self::Intensity, Some(light)) => {
+                light.intensity = value * value * light.kind.max_intensity();
+            }
+            (Self::Yaw, Some(light)) => light.yaw = value * 360.0,
+            (Self::Pitch, Some(light)) => light.pitch = value * 180.0 - 90.0,
+            (Self::Distance, Some(light)) => light.distance = 0.5 + value * 49.5,
+        }
+    }
+
+    pub fn label(self, rig: &LightRig) -> String {
+        match (self, rig.selected_light()) {
+            (Self::ShadowDistance, _) => format!("Shadow distance {:.0} m", rig.shadow_distance),
+            (_, None) => "-".to_string(),
+            (Self::Red, Some(light)) => format!("Red {:.2}", light.color.red),
+            (Self::Green, Some(light)) => format!("Green {:.2}", light.color.green),
+            (Self::Blue, Some(light)) => format!("Blue {:.2}", light.color.blue),
+            (Self::Intensity, Some(light)) => format!(
+                "Intensity {:.0} {}",
+                light.intensity,
+                light.kind.intensity_unit()
+            ),
+            (Self::Yaw, Some(light)) => format!("Yaw {:.0} deg", light.yaw),
+            (Self::Pitch, Some(light)) => format!("Pitch {:.0} deg", light.pitch),
+            (Self::Distance, Some(light)) => format!("Distance {:.1} m", light.distance),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+/// A material of the loaded glTF file
+#[derive(Clone, Debug)]
+pub struct MaterialEntry {
+    pub name: String,
+    pub handle: Handle<StandardMaterial>,
+    /// One line summary of the material's factors and flags
+    pub summary: String,
+}
+
+/// A texture image referenced by the materials of the loaded glTF file
+#[derive(Clone, Debug)]
+pub struct TextureEntry {
+    pub label: String,
+    pub handle: Handle<Image>,
+    pub width: u32,
+    pub height: u32,
+    pub format: String,
+    pub is_srgb: bool,
+    /// Materials using the texture, with the slot it is used in
+    pub used_by: Vec<String>,
+}
+
+/// Resource for the material and texture browser panel
+#[derive(Resource, Default)]
+pub struct MaterialBrowser {
+    /// glTF asset the lists were built from
+    pub source: Option<AssetId<Gltf>>,
+    pub materials: Vec<MaterialEntry>,
+    pub textures: Vec<TextureEntry>,
+    /// Material whose meshes are highlighted in the viewport
+    pub selected: Option<usize>,
+}
+
+impl MaterialBrowser {
+    pub fn selected_material(&self) -> Option<&MaterialEntry> {
+        self.materials.get(self.selected?)
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Materials of a mesh primitive listed in its `KHR_materials_variants` mappings
+#[derive(Clone, Debug, Default)]
+pub struct PrimitiveVariants {
+    /// Material index the primitive references directly, `None` for the default material
+    pub default_material: Option<usize>,
+    /// Material index by variant index
+    pub materials: HashMap<usize, usize>,
+}
+
+impl PrimitiveVariants {
+    /// Material index for `variant`, `None` for the default material
+    ///
+    /// Variants that don't map the primitive fall back to the material it references directly.
+    pub fn material_index(&self, variant: Option<usize>) -> Option<usize> {
+        variant
+            .and_then(|variant| self.materials.get(&variant).copied())
+            .or(self.default_material)
+    }
+}
+
+/// Resource for the `KHR_materials_variants` of the loaded glTF file
+#[derive(Resource, Default)]
+pub struct MaterialVariants {
+    /// glTF asset the variants were read from
+    pub source: Option<AssetId<Gltf>>,
+    pub names: Vec<String>,
+    /// Primitives with variant mappings, by glTF mesh and primitive index
+    pub primitives: HashMap<(usize, usize), PrimitiveVariants>,
+    /// Active variant, `None` for the materials the primitives reference directly
+    pub selected: Option<usize>,
+}
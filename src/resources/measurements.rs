@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+/// Two points placed on the model's surface
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl Measurement {
+    pub fn distance(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+}
+
+/// Resource for the distance measurement tool
+#[derive(Resource)]
+pub struct Measurements {
+    /// Viewport clicks place measurement points instead of selecting nodes
+    pub active: bool,
+    /// Move points to the nearest corner of the clicked triangle when it is close to the cursor
+    pub snap: bool,
+    /// First point of the measurement being placed
+    pub pending: Option<Vec3>,
+    /// Measurements kept until cleared
+    pub completed: Vec<Measurement>,
+}
+
+impl Default for Measurements {
+    fn default() -> Self {
+        Self {
+            active: false,
+            snap: true,
+            pending: None,
+            completed: Vec::new(),
+        }
+    }
+}
+
+impl Measurements {
+    /// Start a measurement at `point`, or finish the pending one there
+    pub fn add_point(&mut self, point: Vec3) {
+        match self.pending.take() {
+            Some(start) => self.completed.push(Measurement { start, end: point }),
+            None => self.pending = Some(point),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.pending = None;
+        self.completed.clear();
+    }
+}
@@ -1,5 +1,27 @@
+mod active_camera;
+mod display_settings;
+mod environment;
+mod light_rig;
+mod material_browser;
+mod material_variants;
+mod measurements;
+mod model_stats;
 mod model_viewer;
+mod morph_targets;
+mod outliner_state;
 mod panel_drag_state;
+mod selection;
 
-pub use model_viewer::ModelViewer;
+pub use active_camera::ActiveCamera;
+pub use display_settings::{DisplayOption, DisplaySettings, MaterialView, ShadingMode};
+pub use environment::{EnvironmentSettings, MAX_ENVIRONMENT_INTENSITY};
+pub use light_rig::{LightField, LightRig, RigLight, RigLightKind};
+pub use material_browser::{MaterialBrowser, MaterialEntry, TextureEntry};
+pub use material_variants::{MaterialVariants, PrimitiveVariants};
+pub use measurements::Measurements;
+pub use model_stats::{ModelStats, TextureStats};
+pub use model_viewer::{AnimationEntry, LoadOptions, LoopMode, ModelViewer, SceneSelector};
+pub use morph_targets::{MorphMesh, MorphTargets};
+pub use outliner_state::OutlinerState;
 pub use panel_drag_state::PanelDragState;
+pub use selection::{PickHit, Selection};
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Size and memory estimate of one texture image
+#[derive(Clone, Debug)]
+pub struct TextureStats {
+    pub label: String,
+    pub width: u32,
+    pub height: u32,
+    pub format: String,
+    pub gpu_bytes: u64,
+}
+
+/// Resource with asset statistics of the loaded glTF file
+#[derive(Resource, Default)]
+pub struct ModelStats {
+    /// glTF asset the statistics were computed from
+    pub source: Option<AssetId<Gltf>>,
+    pub meshes: usize,
+    pub primitives: usize,
+    pub vertices: usize,
+    pub triangles: usize,
+    pub materials: usize,
+    pub textures: Vec<TextureStats>,
+    pub skins: usize,
+    pub joints: usize,
+    pub animations: usize,
+    /// Total keyframes over all animation channels, if the glTF document is available
+    pub keyframes: Option<usize>,
+}
+
+impl ModelStats {
+    /// Estimated GPU memory of all textures in bytes
+    pub fn texture_bytes(&self) -> u64 {
+        self.textures.iter().map(|texture| texture.gpu_bytes).sum()
+    }
+}
@@ -1,15 +1,228 @@
 use bevy::prelude::*;
 use std::path::PathBuf;
 
+/// Order in which the animation list is displayed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationSortOrder {
+    #[default]
+    FileOrder,
+    Alphabetical,
+    Duration,
+}
+
+impl AnimationSortOrder {
+    /// Cycle to the next sort order
+    pub fn next(self) -> Self {
+        match self {
+            Self::FileOrder => Self::Alphabetical,
+            Self::Alphabetical => Self::Duration,
+            Self::Duration => Self::FileOrder,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::FileOrder => "File order",
+            Self::Alphabetical => "Name",
+            Self::Duration => "Duration",
+        }
+    }
+}
+
+/// Frame rate used by the timeline for frame numbers and stepping
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrameRate {
+    Fps24,
+    #[default]
+    Fps30,
+    Fps60,
+}
+
+impl FrameRate {
+    /// Cycle to the next frame rate
+    pub fn next(self) -> Self {
+        match self {
+            Self::Fps24 => Self::Fps30,
+            Self::Fps30 => Self::Fps60,
+            Self::Fps60 => Self::Fps24,
+        }
+    }
+
+    pub fn fps(self) -> f32 {
+        match self {
+            Self::Fps24 => 24.0,
+            Self::Fps30 => 30.0,
+            Self::Fps60 => 60.0,
+        }
+    }
+
+    /// Frame index at `time` seconds
+    pub fn frame_at(self, time: f32) -> i32 {
+        (time * self.fps()).round() as i32
+    }
+}
+
+/// What the active clip does when it reaches its end
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoopMode {
+    #[default]
+    Loop,
+    Once,
+    PingPong,
+}
+
+impl LoopMode {
+    /// Cycle to the next loop mode
+    pub fn next(self) -> Self {
+        match self {
+            Self::Loop => Self::Once,
+            Self::Once => Self::PingPong,
+            Self::PingPong => Self::Loop,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Loop => "Loop",
+            Self::Once => "Once",
+            Self::PingPong => "Ping-pong",
+        }
+    }
+}
+
+/// Playback speeds selectable from the panel, slowest first
+const SPEED_STEPS: [f32; 9] = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0];
+
+/// Crossfade durations in seconds selectable from the panel, zero disables blending
+const CROSSFADE_STEPS: [f32; 6] = [0.0, 0.1, 0.25, 0.5, 1.0, 2.0];
+
+/// A single animation list entry, tied to the graph node of its clip
+#[derive(Clone, Debug)]
+pub struct AnimationEntry {
+    pub name: String,
+    pub node: AnimationNodeIndex,
+    /// Index of the animation in the glTF file
+    pub gltf_index: usize,
+    pub duration: f32,
+}
+
+/// Scene to spawn from a glTF file, by index or by name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SceneSelector {
+    Index(usize),
+    Name(String),
+}
+
+/// Options applied once to the next model that finishes loading
+#[derive(Clone, Debug, Default)]
+pub struct LoadOptions {
+    pub scene: Option<SceneSelector>,
+    pub animation: Option<String>,
+    pub paused: bool,
+}
+
 /// Resource to track the current model and animations
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct ModelViewer {
     pub current_model: Option<Entity>,
     pub model_path: Option<PathBuf>,
     pub gltf_handle: Option<Handle<Gltf>>,
-    pub animations: Vec<AnimationNodeIndex>,
-    pub animation_names: Vec<String>,
+    /// Display names of the scenes in the glTF file, in file order
+    pub scenes: Vec<String>,
+    pub current_scene: usize,
+    pub animations: Vec<AnimationEntry>,
+    pub sort_order: AnimationSortOrder,
     pub graph_handle: Option<Handle<AnimationGraph>>,
     pub current_animation: usize,
     pub is_playing: bool,
+    pub speed: f32,
+    pub reverse: bool,
+    pub loop_mode: LoopMode,
+    /// Seconds to blend from the previous clip when a new one is selected
+    pub crossfade: f32,
+    pub frame_rate: FrameRate,
+    pub load_options: LoadOptions,
+}
+
+impl Default for ModelViewer {
+    fn default() -> Self {
+        Self {
+            current_model: None,
+            model_path: None,
+            gltf_handle: None,
+            scenes: Vec::new(),
+            current_scene: 0,
+            animations: Vec::new(),
+            sort_order: AnimationSortOrder::default(),
+            graph_handle: None,
+            current_animation: 0,
+            is_playing: false,
+            speed: 1.0,
+            reverse: false,
+            loop_mode: LoopMode::default(),
+            crossfade: 0.0,
+            frame_rate: FrameRate::default(),
+            load_options: LoadOptions::default(),
+        }
+    }
+}
+
+impl ModelViewer {
+    /// The currently selected animation entry, if any
+    pub fn current_entry(&self) -> Option<&AnimationEntry> {
+        self.animations.get(self.current_animation)
+    }
+
+    /// Sort the animation list by `sort_order`, keeping the selection on the same clip
+    pub fn sort_animations(&mut self) {
+        let selected = self.current_entry().map(|entry| entry.node);
+
+        match self.sort_order {
+            AnimationSortOrder::FileOrder => {
+                self.animations.sort_by_key(|entry| entry.gltf_index);
+            }
+            AnimationSortOrder::Alphabetical => {
+                self.animations.sort_by(|a, b| {
+                    a.name
+                        .to_lowercase()
+                        .cmp(&b.name.to_lowercase())
+                        .then(a.gltf_index.cmp(&b.gltf_index))
+                });
+            }
+            AnimationSortOrder::Duration => {
+                self.animations.sort_by(|a, b| {
+                    a.duration
+                        .total_cmp(&b.duration)
+                        .then(a.gltf_index.cmp(&b.gltf_index))
+                });
+            }
+        }
+
+        if let Some(node) = selected {
+            self.current_animation = self
+                .animations
+                .iter()
+                .position(|entry| entry.node == node)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Move the playback speed `steps` positions along the speed steps
+    pub fn change_speed(&mut self, steps: i32) {
+        let current = SPEED_STEPS
+            .iter()
+            .position(|&speed| speed >= self.speed)
+            .unwrap_or(SPEED_STEPS.len() - 1);
+        let index = (current as i32 + steps).clamp(0, SPEED_STEPS.len() as i32 - 1);
+        self.speed = SPEED_STEPS[index as usize];
+    }
+
+    /// Cycle to the next crossfade duration
+    pub fn next_crossfade(&mut self) {
+        let next = CROSSFADE_STEPS
+            .iter()
+            .position(|&duration| duration > self.crossfade)
+            .unwrap_or(0);
+        self.crossfade = CROSSFADE_STEPS[next];
+    }
 }
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// A node of the loaded model with morph target weights
+#[derive(Clone, Debug)]
+pub struct MorphMesh {
+    /// Entity holding the `MorphWeights`
+    pub entity: Entity,
+    pub name: String,
+    /// Target names from the mesh's `targetNames` extras, or numbered
+    pub targets: Vec<String>,
+}
+
+/// Resource for the morph target panel
+#[derive(Resource)]
+pub struct MorphTargets {
+    /// Model the meshes were collected from
+    pub model: Option<Entity>,
+    pub meshes: Vec<MorphMesh>,
+    /// Let the playing animation override the slider weights
+    pub animation_override: bool,
+}
+
+impl Default for MorphTargets {
+    fn default() -> Self {
+        Self {
+            model: None,
+            meshes: Vec::new(),
+            animation_override: true,
+        }
+    }
+}
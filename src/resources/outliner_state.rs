@@ -0,0 +1,11 @@
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+
+/// Resource for the scene outliner's tree state
+#[derive(Resource, Default)]
+pub struct OutlinerState {
+    /// Nodes whose children are hidden in the tree
+    pub collapsed: HashSet<Entity>,
+    /// Model root and node count the tree was last built from
+    pub built_for: Option<(Entity, usize)>,
+}
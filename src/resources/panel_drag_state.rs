@@ -3,6 +3,7 @@ use bevy::prelude::*;
 /// Resource for tracking panel dragging state
 #[derive(Resource, Default)]
 pub struct PanelDragState {
-    pub dragging: bool,
+    /// Panel currently being dragged, if any
+    pub panel: Option<Entity>,
     pub offset: Vec2,
 }
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+/// Where a viewport click hit the model
+#[derive(Clone, Copy, Debug)]
+pub struct PickHit {
+    /// Mesh entity that was hit
+    pub entity: Entity,
+    /// World-space hit point
+    pub point: Vec3,
+    /// Index of the hit triangle in the mesh
+    pub triangle: Option<usize>,
+}
+
+/// Resource for the scene node selected in the outliner or the viewport
+#[derive(Resource, Default)]
+pub struct Selection {
+    pub entity: Option<Entity>,
+    /// Last viewport pick, only describing `entity` while the two match
+    pub pick: Option<PickHit>,
+}
+
+impl Selection {
+    /// Pick that selected the current entity, if it was selected in the viewport
+    pub fn current_pick(&self) -> Option<&PickHit> {
+        self.pick
+            .as_ref()
+            .filter(|pick| Some(pick.entity) == self.entity)
+    }
+}
@@ -1,13 +1,17 @@
+use bevy::animation::RepeatAnimation;
 use bevy::prelude::*;
+use std::time::Duration;
 
 use crate::components::AnimationsLoaded;
-use crate::resources::ModelViewer;
+use crate::resources::{AnimationEntry, LoopMode, ModelViewer};
 
+#[allow(clippy::too_many_arguments)]
 pub fn setup_animations(
     mut commands: Commands,
     mut viewer: ResMut<ModelViewer>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
     gltf_assets: Res<Assets<Gltf>>,
+    clips: Res<Assets<AnimationClip>>,
     children_query: Query<&Children>,
     animation_player_query: Query<Entity, With<AnimationPlayer>>,
     animations_loaded_query: Query<(), With<AnimationsLoaded>>,
@@ -65,61 +69,164 @@ pub fn setup_animations(
 
     info!("Found {} animations in GLTF", gltf.animations.len());
 
-    // Create animation graph and get animation names
+    // Create animation graph; node indices follow glTF animation order
     let (graph, indices) = AnimationGraph::from_clips(gltf.animations.iter().cloned());
     let graph_handle = graphs.add(graph);
 
-    // Get animation names from the named_animations map
-    let mut animation_names: Vec<String> = Vec::new();
-
-    // Use named_animations directly - it maps name to handle
-    for (name, _handle) in gltf.named_animations.iter() {
-        animation_names.push(name.to_string());
+    // Resolve names by glTF animation index, matching on the clip handle
+    let mut names: Vec<Option<String>> = vec![None; gltf.animations.len()];
+    for (name, handle) in gltf.named_animations.iter() {
+        if let Some(index) = gltf.animations.iter().position(|clip| clip == handle) {
+            names[index] = Some(name.to_string());
+        }
     }
 
-    // Sort alphabetically for consistent ordering
-    animation_names.sort();
-
-    // If no named animations, create default names
-    if animation_names.is_empty() {
-        for i in 0..gltf.animations.len() {
-            animation_names.push(format!("Animation {}", i + 1));
+    viewer.animations = gltf
+        .animations
+        .iter()
+        .zip(indices)
+        .zip(names)
+        .enumerate()
+        .map(|(i, ((clip, node), name))| AnimationEntry {
+            name: name.unwrap_or_else(|| format!("Animation {}", i + 1)),
+            node,
+            gltf_index: i,
+            duration: clips.get(clip).map_or(0.0, AnimationClip::duration),
+        })
+        .collect();
+    viewer.sort_animations();
+    viewer.current_animation = 0;
+
+    info!(
+        "Animation names: {:?}",
+        viewer
+            .animations
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    // Apply the animation requested at load time, if any
+    if let Some(name) = viewer.load_options.animation.take() {
+        let index = viewer
+            .animations
+            .iter()
+            .position(|entry| entry.name == name);
+        match index {
+            Some(index) => viewer.current_animation = index,
+            None => warn!("Animation \"{}\" not found", name),
         }
     }
 
-    info!("Animation names: {:?}", animation_names);
-
-    viewer.animations = indices;
-    viewer.animation_names = animation_names;
     viewer.graph_handle = Some(graph_handle.clone());
-    viewer.is_playing = true;
+    viewer.is_playing = !std::mem::take(&mut viewer.load_options.paused);
 
     // Add the graph to the animation player
-    commands
-        .entity(player_entity)
-        .insert((AnimationGraphHandle(graph_handle), AnimationsLoaded));
+    commands.entity(player_entity).insert((
+        AnimationGraphHandle(graph_handle),
+        AnimationTransitions::new(),
+        AnimationsLoaded,
+    ));
 }
 
 pub fn control_animations(
-    viewer: Res<ModelViewer>,
-    mut animation_players: Query<(&mut AnimationPlayer, &AnimationGraphHandle)>,
+    mut viewer: ResMut<ModelViewer>,
+    mut animation_players: Query<(&mut AnimationPlayer, &mut AnimationTransitions)>,
+    mut bounced: Local<bool>,
+    mut was_playing: Local<bool>,
 ) {
-    if viewer.animations.is_empty() {
+    let Some(entry) = viewer.current_entry() else {
         return;
     };
+    let (animation_index, duration) = (entry.node, entry.duration);
 
-    for (mut player, _graph) in &mut animation_players {
-        let animation_index = viewer.animations[viewer.current_animation];
-
-        if viewer.is_playing {
-            if !player.is_playing_animation(animation_index) {
-                // Stop all other animations and play the selected one
+    // Ping-pong plays the clip once per direction and bounces manually
+    let repeat = match viewer.loop_mode {
+        LoopMode::Loop => RepeatAnimation::Forever,
+        LoopMode::Once | LoopMode::PingPong => RepeatAnimation::Never,
+    };
+    let crossfade = Duration::from_secs_f32(viewer.crossfade);
+    let started = viewer.is_playing && !*was_playing;
+    *was_playing = viewer.is_playing;
+
+    let mut finished = false;
+    for (mut player, mut transitions) in &mut animation_players {
+        // Keep the selected clip active even while paused so it can be scrubbed
+        if !player.is_playing_animation(animation_index) {
+            // Paused clips are not faded out by the transitions, so stop them outright
+            if crossfade.is_zero() || player.all_paused() {
                 player.stop_all();
-                player.play(animation_index).repeat();
             }
+            let active = transitions
+                .play(&mut player, animation_index, crossfade)
+                .set_repeat(repeat);
+            if viewer.reverse {
+                active.set_seek_time(duration);
+            }
+            *bounced = false;
+        }
+        let Some(active) = player.animation_mut(animation_index) else {
+            continue;
+        };
+
+        // Loops played under a previous mode must not count as completions
+        if active.repeat_mode() != repeat {
+            let time = active.seek_time();
+            active.replay();
+            active.set_repeat(repeat).set_seek_time(time);
+        }
+
+        if active.is_finished() {
+            if viewer.loop_mode == LoopMode::PingPong {
+                // Turn around at whichever end was reached
+                *bounced = !*bounced;
+                let time = active.seek_time().clamp(0.0, duration);
+                active.replay();
+                active.set_seek_time(time);
+            } else if started {
+                // Pressing play on a held clip starts it over
+                active.replay();
+                active.set_seek_time(if viewer.reverse { duration } else { 0.0 });
+            } else {
+                finished = true;
+            }
+        }
+
+        let speed = if viewer.reverse != *bounced {
+            -viewer.speed
+        } else {
+            viewer.speed
+        };
+        active.set_speed(speed);
+
+        if viewer.is_playing {
             player.resume_all();
         } else {
             player.pause_all();
         }
     }
+
+    // Clips played once hold their last frame and stop
+    if finished && viewer.is_playing {
+        viewer.is_playing = false;
+        *was_playing = false;
+    }
+}
+
+pub fn playback_shortcuts(keys: Res<ButtonInput<KeyCode>>, mut viewer: ResMut<ModelViewer>) {
+    if keys.just_pressed(KeyCode::Space) {
+        viewer.is_playing = !viewer.is_playing;
+    }
+    if keys.just_pressed(KeyCode::Minus) {
+        viewer.change_speed(-1);
+    }
+    if keys.just_pressed(KeyCode::Equal) {
+        viewer.change_speed(1);
+    }
+    if keys.just_pressed(KeyCode::KeyR) {
+        viewer.reverse = !viewer.reverse;
+    }
+    if keys.just_pressed(KeyCode::KeyL) {
+        viewer.loop_mode = viewer.loop_mode.next();
+    }
 }
@@ -0,0 +1,138 @@
+use bevy::camera::primitives::Aabb;
+use bevy::math::bounding::{Aabb3d, BoundingVolume};
+use bevy::prelude::*;
+
+use super::guides::OverlayGizmos;
+use crate::components::DimensionLabel;
+use crate::resources::{DisplaySettings, ModelViewer, Selection};
+
+const MODEL_BOUNDS_COLOR: Color = Color::srgb(0.95, 0.95, 0.5);
+const SELECTION_BOUNDS_COLOR: Color = Color::srgb(0.3, 0.9, 1.0);
+
+/// World-space bounds of all meshes at or below `root`
+///
+/// Returns `None` until the scene has spawned and mesh bounds have been calculated.
+pub fn model_bounds(
+    root: Entity,
+    children: &Query<&Children>,
+    mesh_bounds: &Query<(&Aabb, &GlobalTransform)>,
+) -> Option<Aabb3d> {
+    std::iter::once(root)
+        .chain(children.iter_descendants(root))
+        .filter_map(|entity| mesh_bounds.get(entity).ok())
+        .map(|(aabb, global)| world_aabb(aabb, global))
+        .reduce(|bounds, aabb| bounds.merge(&aabb))
+}
+
+/// Transform of a unit cuboid covering a local mesh AABB, for drawing it as an oriented box
+pub fn aabb_box(aabb: &Aabb, global: &GlobalTransform) -> GlobalTransform {
+    global.mul_transform(
+        Transform::from_translation(aabb.center.into())
+            .with_scale(Vec3::from(aabb.half_extents) * 2.0),
+    )
+}
+
+/// Transform a local mesh AABB into a world-space AABB enclosing it
+pub fn world_aabb(aabb: &Aabb, global: &GlobalTransform) -> Aabb3d {
+    let affine = global.affine();
+    let center = affine.transform_point3a(aabb.center);
+    // Each world axis extent is the sum of the projected local extents
+    let half_size = affine.matrix3.abs() * aabb.half_extents;
+    Aabb3d {
+        min: center - half_size,
+        max: center + half_size,
+    }
+}
+
+/// Draw the world-space bounding boxes of the model and the selected node, labeled with their
+/// size in meters
+#[allow(clippy::too_many_arguments)]
+pub fn draw_bounds(
+    settings: Res<DisplaySettings>,
+    viewer: Res<ModelViewer>,
+    selection: Res<Selection>,
+    children: Query<&Children>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform)>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut labels: Query<(&DimensionLabel, &mut Text, &mut Node)>,
+    mut gizmos: Gizmos<OverlayGizmos>,
+) {
+    let camera = cameras.iter().find(|(camera, _)| camera.is_active);
+    let model = viewer
+        .current_model
+        .filter(|_| settings.bounds)
+        .and_then(|model| model_bounds(model, &children, &mesh_bounds));
+    // The model root is outlined already, so only nodes inside it get a box of their own
+    let selected = selection
+        .entity
+        .filter(|entity| settings.bounds && Some(*entity) != viewer.current_model)
+        .and_then(|entity| model_bounds(entity, &children, &mesh_bounds));
+
+    if let Some(bounds) = model {
+        gizmos.cuboid(box_transform(&bounds), MODEL_BOUNDS_COLOR);
+    }
+    if let Some(bounds) = selected {
+        gizmos.cuboid(box_transform(&bounds), SELECTION_BOUNDS_COLOR);
+    }
+
+    for (label, mut text, mut node) in &mut labels {
+        let placement = match label {
+            DimensionLabel::Selection => selected.map(|bounds| {
+                let size = bounds.max - bounds.min;
+                let anchor = Vec3::new(bounds.center().x, bounds.max.y, bounds.center().z);
+                let value = format!(
+                    "{} x {} x {}",
+                    format_meters(size.x),
+                    format_meters(size.y),
+                    format_meters(size.z)
+                );
+                (anchor, value)
+            }),
+            // Each model dimension sits at the middle of a front edge along its axis
+            _ => model.map(|bounds| {
+                let (min, max, center) = (bounds.min, bounds.max, bounds.center());
+                match label {
+                    DimensionLabel::Width => (
+                        Vec3::new(center.x, min.y, max.z),
+                        format!("W {}", format_meters(max.x - min.x)),
+                    ),
+                    DimensionLabel::Height => (
+                        Vec3::new(max.x, center.y, max.z),
+                        format!("H {}", format_meters(max.y - min.y)),
+                    ),
+                    _ => (
+                        Vec3::new(max.x, min.y, center.z),
+                        format!("D {}", format_meters(max.z - min.z)),
+                    ),
+                }
+            }),
+        };
+
+        let screen = placement
+            .zip(camera)
+            .and_then(|((anchor, value), (camera, transform))| {
+                let position = camera.world_to_viewport(transform, anchor).ok()?;
+                Some((position, value))
+            });
+        let Some((position, value)) = screen else {
+            node.display = Display::None;
+            continue;
+        };
+        if **text != value {
+            **text = value;
+        }
+        node.display = Display::Flex;
+        node.left = Val::Px(position.x + 4.0);
+        node.top = Val::Px(position.y - 8.0);
+    }
+}
+
+/// Transform of a unit cuboid filling a world-space box
+fn box_transform(bounds: &Aabb3d) -> Transform {
+    Transform::from_translation(bounds.center().into()).with_scale((bounds.max - bounds.min).into())
+}
+
+/// Length in meters, with millimeter precision
+fn format_meters(length: f32) -> String {
+    format!("{length:.3} m")
+}
@@ -1,34 +1,321 @@
+use bevy::camera::ScalingMode;
+use bevy::camera::primitives::Aabb;
+use bevy::math::bounding::BoundingVolume;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
 use bevy_panorbit_camera::PanOrbitCamera;
 
-use crate::components::{AnimationScrollArea, DraggablePanel};
+use super::bounds::model_bounds;
+use crate::components::{CameraFramed, DraggablePanel, ScrollArea};
+use crate::resources::{ActiveCamera, ModelViewer};
+
+/// Extra room around the model when framing, as a fraction of its size
+const FRAME_MARGIN: f32 = 0.1;
 
 pub fn disable_camera_on_ui_hover(
     panel_query: Query<&Interaction, With<DraggablePanel>>,
-    scroll_query: Query<&RelativeCursorPosition, With<AnimationScrollArea>>,
+    scroll_query: Query<&RelativeCursorPosition, With<ScrollArea>>,
     mut camera_query: Query<&mut PanOrbitCamera>,
 ) {
-    let mut over_ui = false;
+    let over_ui = cursor_over_ui(&panel_query, &scroll_query);
+
+    // Disable/enable camera based on UI hover
+    for mut camera in &mut camera_query {
+        camera.enabled = !over_ui;
+    }
+}
+
+/// Whether the cursor is over a panel or one of its scroll areas
+pub fn cursor_over_ui(
+    panel_query: &Query<&Interaction, With<DraggablePanel>>,
+    scroll_query: &Query<&RelativeCursorPosition, With<ScrollArea>>,
+) -> bool {
+    panel_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+        || scroll_query.iter().any(RelativeCursorPosition::cursor_over)
+}
+
+/// Point the orbit camera at the model once its bounds are known
+///
+/// Runs again whenever [`CameraFramed`] is removed from the model root.
+pub fn frame_model(
+    mut commands: Commands,
+    viewer: Res<ModelViewer>,
+    framed: Query<(), With<CameraFramed>>,
+    children: Query<&Children>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform)>,
+    mut camera_query: Query<(&mut PanOrbitCamera, &mut Projection)>,
+) {
+    let Some(model_entity) = viewer.current_model else {
+        return;
+    };
+    if framed.contains(model_entity) {
+        return;
+    }
+    let Some(bounds) = model_bounds(model_entity, &children, &mesh_bounds) else {
+        // Meshes not spawned yet
+        return;
+    };
+
+    // Fit the bounding sphere so the model stays in view from every angle
+    let focus = Vec3::from(bounds.center());
+    let radius = bounds.half_size().length().max(0.001) * (1.0 + FRAME_MARGIN);
+
+    for (mut camera, mut projection) in &mut camera_query {
+        let distance = match projection.as_mut() {
+            Projection::Perspective(perspective) => {
+                let half_fov = perspective.fov * 0.5;
+                let half_fov_x = (half_fov.tan() * perspective.aspect_ratio).atan();
+                let distance = radius / half_fov.min(half_fov_x).sin();
+                fit_clip_planes(perspective, distance);
+                distance
+            }
+            // The orbit camera uses the radius as the orthographic scale
+            Projection::Orthographic(orthographic) => {
+                let area = orthographic.area;
+                let aspect = area.width() / area.height();
+                let aspect = if aspect.is_normal() { aspect } else { 1.0 };
+                2.0 * radius / aspect.min(1.0)
+            }
+            _ => radius * 3.0,
+        };
+
+        camera.target_focus = focus;
+        camera.target_radius = distance;
+        camera.zoom_lower_limit = camera.zoom_lower_limit.min(distance * 0.01);
+        camera.force_update = true;
+    }
 
-    // Check if hovering over the panel
-    for interaction in &panel_query {
-        if *interaction != Interaction::None {
-            over_ui = true;
-            break;
+    commands.entity(model_entity).insert(CameraFramed);
+}
+
+/// Frame the model again on the next update
+pub fn reframe_model(commands: &mut Commands, viewer: &ModelViewer) {
+    if let Some(model_entity) = viewer.current_model {
+        commands.entity(model_entity).remove::<CameraFramed>();
+    }
+}
+
+/// Keep tiny and huge models inside the clipping range
+fn fit_clip_planes(perspective: &mut PerspectiveProjection, distance: f32) {
+    perspective.near = perspective.near.min(distance * 0.01);
+    perspective.far = perspective.far.max(distance * 100.0);
+}
+
+/// Switch between perspective and orthographic projection
+///
+/// The height visible at the focus point is kept, so the model stays the same size on screen.
+pub fn toggle_projection(camera: &mut PanOrbitCamera, projection: &mut Projection) {
+    match projection {
+        Projection::Perspective(perspective) => {
+            let height = 2.0 * camera.target_radius * (perspective.fov * 0.5).tan();
+            let far = perspective.far;
+            *projection = Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: 1.0,
+                },
+                far,
+                ..OrthographicProjection::default_3d()
+            });
+            set_radius(camera, height);
+        }
+        Projection::Orthographic(_) => {
+            let mut perspective = PerspectiveProjection::default();
+            let distance = camera.target_radius / (2.0 * (perspective.fov * 0.5).tan());
+            fit_clip_planes(&mut perspective, distance);
+            *projection = Projection::Perspective(perspective);
+            set_radius(camera, distance);
         }
+        _ => {}
+    }
+}
+
+/// Jump to a new radius, skipping the zoom smoothing
+fn set_radius(camera: &mut PanOrbitCamera, radius: f32) {
+    camera.radius = Some(radius);
+    camera.target_radius = radius;
+    camera.force_update = true;
+}
+
+/// Render from the selected glTF camera, or the orbit camera when none is selected
+///
+/// glTF files activate their first camera on spawn, so this also keeps those off by default.
+pub fn sync_active_camera(
+    mut active: ResMut<ActiveCamera>,
+    mut camera_query: Query<(Entity, &mut Camera, Has<PanOrbitCamera>), With<Camera3d>>,
+) {
+    // The selected camera is despawned along with its model
+    if let Some(entity) = active.scene_camera
+        && !camera_query.contains(entity)
+    {
+        active.scene_camera = None;
     }
 
-    // Check if cursor is inside scroll area
-    for rel_pos in &scroll_query {
-        if rel_pos.cursor_over() {
-            over_ui = true;
-            break;
+    for (entity, mut camera, is_orbit) in &mut camera_query {
+        let is_active = if is_orbit {
+            active.scene_camera.is_none()
+        } else {
+            active.scene_camera == Some(entity)
+        };
+        if camera.is_active != is_active {
+            camera.is_active = is_active;
         }
     }
+}
 
-    // Disable/enable camera based on UI hover
-    for mut camera in &mut camera_query {
-        camera.enabled = !over_ui;
+/// Cameras from the loaded glTF scene, in spawn order
+pub fn scene_cameras(
+    camera_query: &Query<Entity, (With<Camera3d>, Without<PanOrbitCamera>)>,
+) -> Vec<Entity> {
+    let mut cameras: Vec<Entity> = camera_query.iter().collect();
+    cameras.sort();
+    cameras
+}
+
+/// Blender-style numpad views: 1 front, 3 right, 7 top, with Ctrl for the opposite side.
+/// Numpad 0 steps through the glTF cameras.
+pub fn camera_shortcuts(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    viewer: Res<ModelViewer>,
+    mut active: ResMut<ActiveCamera>,
+    mut camera_query: Query<(&mut PanOrbitCamera, &mut Projection)>,
+    scene_camera_query: Query<Entity, (With<Camera3d>, Without<PanOrbitCamera>)>,
+) {
+    if keyboard.just_pressed(KeyCode::Numpad0) {
+        active.cycle(&scene_cameras(&scene_camera_query));
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        reframe_model(&mut commands, &viewer);
+        active.scene_camera = None;
+    }
+
+    let opposite = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let preset = if keyboard.just_pressed(KeyCode::Numpad1) {
+        Some(if opposite {
+            CameraPreset::Back
+        } else {
+            CameraPreset::Front
+        })
+    } else if keyboard.just_pressed(KeyCode::Numpad3) {
+        Some(if opposite {
+            CameraPreset::Left
+        } else {
+            CameraPreset::Right
+        })
+    } else if keyboard.just_pressed(KeyCode::Numpad7) {
+        Some(if opposite {
+            CameraPreset::Bottom
+        } else {
+            CameraPreset::Top
+        })
+    } else {
+        None
+    };
+    let toggle = keyboard.just_pressed(KeyCode::Numpad5);
+    // Orbit camera controls switch back to the orbit camera
+    if preset.is_some() || toggle {
+        active.scene_camera = None;
+    }
+
+    for (mut camera, mut projection) in &mut camera_query {
+        if let Some(preset) = preset {
+            preset.animate(&mut camera);
+        }
+        if toggle {
+            toggle_projection(&mut camera, &mut projection);
+        }
+    }
+}
+
+/// Fixed viewing angles for the orbit camera
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraPreset {
+    Front,
+    Back,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Iso,
+}
+
+impl CameraPreset {
+    pub const ALL: [Self; 7] = [
+        Self::Front,
+        Self::Back,
+        Self::Left,
+        Self::Right,
+        Self::Top,
+        Self::Bottom,
+        Self::Iso,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Front => "front",
+            Self::Back => "back",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Iso => "iso",
+        }
+    }
+
+    /// Button label
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Front => "Front",
+            Self::Back => "Back",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Top => "Top",
+            Self::Bottom => "Bottom",
+            Self::Iso => "Iso",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Orbit camera yaw and pitch in radians
+    pub fn yaw_pitch(self) -> (f32, f32) {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        match self {
+            Self::Front => (0.0, 0.0),
+            Self::Back => (PI, 0.0),
+            Self::Left => (-FRAC_PI_2, 0.0),
+            Self::Right => (FRAC_PI_2, 0.0),
+            Self::Top => (0.0, FRAC_PI_2),
+            Self::Bottom => (0.0, -FRAC_PI_2),
+            // Matches the initial camera position at (3, 3, 3)
+            Self::Iso => (FRAC_PI_4, (1.0 / 2.0_f32.sqrt()).atan()),
+        }
+    }
+
+    /// Move the camera to this preset immediately, without interpolation
+    pub fn snap(self, camera: &mut PanOrbitCamera) {
+        let (yaw, pitch) = self.yaw_pitch();
+        camera.yaw = Some(yaw);
+        camera.pitch = Some(pitch);
+        camera.target_yaw = yaw;
+        camera.target_pitch = pitch;
+        camera.force_update = true;
+    }
+
+    /// Rotate the camera to this preset using the orbit camera's smoothing
+    pub fn animate(self, camera: &mut PanOrbitCamera) {
+        use std::f32::consts::{PI, TAU};
+        let (yaw, pitch) = self.yaw_pitch();
+        // Yaw is unbounded, so take the short way around from the current angle
+        let delta = (yaw - camera.target_yaw).rem_euclid(TAU);
+        camera.target_yaw += if delta > PI { delta - TAU } else { delta };
+        camera.target_pitch = pitch;
     }
 }
@@ -0,0 +1,250 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::core_pipeline::Skybox;
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
+};
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::tasks::futures::check_ready;
+use bevy_file_dialog::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+use crate::components::EnvironmentFile;
+use crate::resources::EnvironmentSettings;
+
+/// Face size of the built-in environment cubemap
+const DEFAULT_CUBEMAP_SIZE: u32 = 64;
+
+/// Largest face size used when converting equirectangular images
+const MAX_CUBEMAP_SIZE: u32 = 1024;
+
+/// Largest cubemap face Bevy can filter into an environment light
+const MAX_SOURCE_CUBEMAP_SIZE: u32 = 8192;
+
+/// Install the built-in environment so lighting works without any files
+pub fn setup_default_environment(
+    mut settings: ResMut<EnvironmentSettings>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    use_default_environment(&mut settings, &mut images);
+}
+
+pub fn use_default_environment(settings: &mut EnvironmentSettings, images: &mut Assets<Image>) {
+    settings.name = "Studio (built-in)".to_string();
+    settings.pending = None;
+    settings.conversion = None;
+    settings.cubemap = Some(images.add(build_cubemap(DEFAULT_CUBEMAP_SIZE, studio_sky)));
+}
+
+pub fn handle_environment_file(
+    mut ev: MessageReader<DialogFileLoaded<EnvironmentFile>>,
+    asset_server: Res<AssetServer>,
+    mut settings: ResMut<EnvironmentSettings>,
+) {
+    for event in ev.read() {
+        let name = event
+            .path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        settings.pending = Some((asset_server.load(event.path.clone()), name));
+        // Dropping the task cancels the conversion of an earlier file
+        settings.conversion = None;
+    }
+}
+
+/// Turn a loaded environment image into a cubemap once it is ready
+///
+/// KTX2 cubemaps are used as they are, equirectangular `.hdr`/`.exr` images are converted on a
+/// background task so large panoramas don't stall the UI.
+pub fn prepare_environment(
+    mut settings: ResMut<EnvironmentSettings>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+) {
+    if let Some(task) = &mut settings.conversion {
+        if let Some(cubemap) = check_ready(task) {
+            settings.conversion = None;
+            finish_environment(&mut settings, images.add(cubemap));
+        }
+        return;
+    }
+
+    let Some((handle, name)) = &settings.pending else {
+        return;
+    };
+    if asset_server.load_state(handle).is_failed() {
+        warn!("Failed to load environment {}", name);
+        settings.pending = None;
+        return;
+    }
+    let Some(image) = images.get(handle) else {
+        return;
+    };
+
+    let size = image.texture_descriptor.size;
+    if size.depth_or_array_layers == 6 {
+        if size.width != size.height
+            || !size.width.is_power_of_two()
+            || size.width > MAX_SOURCE_CUBEMAP_SIZE
+        {
+            warn!(
+                "Environment {} has {}x{} faces, cubemaps need square power-of-two faces up to {}",
+                name, size.width, size.height, MAX_SOURCE_CUBEMAP_SIZE
+            );
+            settings.pending = None;
+            return;
+        }
+        let handle = handle.clone();
+        if let Some(image) = images.get_mut(&handle) {
+            image.texture_view_descriptor = Some(TextureViewDescriptor {
+                dimension: Some(TextureViewDimension::Cube),
+                ..default()
+            });
+        }
+        finish_environment(&mut settings, handle);
+    } else {
+        // Faces a quarter of the panorama width keep roughly the source resolution
+        let face_size = (image.width() / 4)
+            .max(1)
+            .next_power_of_two()
+            .min(MAX_CUBEMAP_SIZE);
+        let panorama = image.clone();
+        settings.conversion = Some(AsyncComputeTaskPool::get().spawn(async move {
+            build_cubemap(face_size, |direction| sample_equirect(&panorama, direction))
+        }));
+    }
+}
+
+fn finish_environment(settings: &mut EnvironmentSettings, cubemap: Handle<Image>) {
+    let (_, name) = settings.pending.take().unwrap_or_default();
+    info!("Loaded environment {}", name);
+    settings.name = name;
+    settings.cubemap = Some(cubemap);
+}
+
+/// Keep the environment light and skybox on every 3D camera in sync with the settings
+///
+/// Bevy filters the cubemap into an `EnvironmentMapLight` only while the camera has none, so a
+/// new cubemap removes the old light, and intensity or rotation changes are written to it directly.
+#[allow(clippy::type_complexity)]
+pub fn apply_environment(
+    mut commands: Commands,
+    settings: Res<EnvironmentSettings>,
+    mut cameras: Query<
+        (
+            Entity,
+            Option<&mut GeneratedEnvironmentMapLight>,
+            Option<&mut EnvironmentMapLight>,
+        ),
+        With<Camera3d>,
+    >,
+    added_cameras: Query<(), Added<Camera3d>>,
+) {
+    if !settings.is_changed() && added_cameras.is_empty() {
+        return;
+    }
+    let Some(cubemap) = &settings.cubemap else {
+        return;
+    };
+
+    for (entity, generated, light) in &mut cameras {
+        let mut camera = commands.entity(entity);
+        match generated {
+            Some(mut generated) if generated.environment_map == *cubemap => {
+                generated.intensity = settings.intensity;
+                generated.rotation = settings.rotation_quat();
+                if let Some(mut light) = light {
+                    light.intensity = settings.intensity;
+                    light.rotation = settings.rotation_quat();
+                }
+            }
+            _ => {
+                camera
+                    .insert(GeneratedEnvironmentMapLight {
+                        environment_map: cubemap.clone(),
+                        intensity: settings.intensity,
+                        rotation: settings.rotation_quat(),
+                        ..default()
+                    })
+                    .remove::<EnvironmentMapLight>();
+            }
+        }
+        if settings.show_skybox {
+            camera.insert(Skybox {
+                image: cubemap.clone(),
+                brightness: settings.intensity,
+                rotation: settings.rotation_quat(),
+            });
+        } else {
+            camera.remove::<Skybox>();
+        }
+    }
+}
+
+/// Render a cubemap by evaluating `sample` for the world direction of every texel
+fn build_cubemap(size: u32, sample: impl Fn(Vec3) -> LinearRgba) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        &[0; 8],
+        TextureFormat::Rgba16Float,
+        // The environment map generator reads the source from the main world
+        RenderAssetUsages::default(),
+    );
+    image.texture_view_descriptor = Some(TextureViewDescriptor {
+        dimension: Some(TextureViewDimension::Cube),
+        ..default()
+    });
+
+    for face in 0..6 {
+        for y in 0..size {
+            for x in 0..size {
+                // Texel center in -1..1, with v pointing down the face
+                let u = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+                let v = (y as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+                let direction = match face {
+                    0 => Vec3::new(1.0, -v, -u),
+                    1 => Vec3::new(-1.0, -v, u),
+                    2 => Vec3::new(u, 1.0, v),
+                    3 => Vec3::new(u, -1.0, -v),
+                    4 => Vec3::new(u, -v, 1.0),
+                    _ => Vec3::new(-u, -v, -1.0),
+                };
+                // Bevy samples cubemaps left-handed, so flip Z to get the world direction
+                let world = Vec3::new(direction.x, direction.y, -direction.z).normalize();
+                let _ = image.set_color_at_3d(x, y, face, sample(world).into());
+            }
+        }
+    }
+
+    image
+}
+
+/// Look up a world direction in an equirectangular panorama
+fn sample_equirect(image: &Image, direction: Vec3) -> LinearRgba {
+    let u = 0.5 + direction.x.atan2(-direction.z) / TAU;
+    let v = direction.y.clamp(-1.0, 1.0).acos() / PI;
+    let x = ((u * image.width() as f32) as u32).min(image.width() - 1);
+    let y = ((v * image.height() as f32) as u32).min(image.height() - 1);
+    image
+        .get_color_at(x, y)
+        .map_or(LinearRgba::BLACK, |color| color.to_linear())
+}
+
+/// Soft studio gradient: bright overhead, neutral horizon, dark floor
+fn studio_sky(direction: Vec3) -> LinearRgba {
+    let zenith = LinearRgba::rgb(1.0, 1.0, 1.05);
+    let horizon = LinearRgba::rgb(0.55, 0.56, 0.6);
+    let ground = LinearRgba::rgb(0.12, 0.12, 0.12);
+    if direction.y >= 0.0 {
+        horizon.mix(&zenith, direction.y.sqrt())
+    } else {
+        horizon.mix(&ground, (-direction.y * 4.0).min(1.0))
+    }
+}
@@ -0,0 +1,237 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::asset::{AssetPath, embedded_asset, embedded_path};
+use bevy::camera::primitives::Aabb;
+use bevy::light::NotShadowCaster;
+use bevy::math::bounding::BoundingVolume;
+use bevy::pbr::wireframe::NoWireframe;
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::bounds::model_bounds;
+use crate::components::GroundPlane;
+use crate::resources::{DisplaySettings, ModelViewer};
+
+/// Grid cells drawn on each side, for both the fine and the coarse grid
+const GRID_CELLS: u32 = 40;
+
+/// Distance of the axis gizmo from the viewport corner, in logical pixels
+const AXIS_GIZMO_INSET: f32 = 50.0;
+
+/// Length of the axis gizmo arrows, in logical pixels
+const AXIS_GIZMO_SIZE: f32 = 35.0;
+
+/// Gizmos drawn on top of the scene
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct OverlayGizmos;
+
+/// Ground plane material that is invisible except where shadows fall on it
+pub type ShadowCatcherMaterial = ExtendedMaterial<StandardMaterial, ShadowCatcher>;
+
+/// Extension turning a lit [`StandardMaterial`] into a shadow catcher
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct ShadowCatcher {
+    /// Opacity of a fully shadowed spot
+    #[uniform(100)]
+    pub strength: f32,
+}
+
+impl MaterialExtension for ShadowCatcher {
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Path(
+            AssetPath::from_path_buf(embedded_path!("shadow_catcher.wgsl")).with_source("embedded"),
+        )
+    }
+}
+
+/// Registers the shadow catcher material and its shader
+pub struct ShadowCatcherPlugin;
+
+impl Plugin for ShadowCatcherPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "shadow_catcher.wgsl");
+        app.add_plugins(MaterialPlugin::<ShadowCatcherMaterial>::default());
+    }
+}
+
+pub fn setup_guides(
+    mut commands: Commands,
+    mut config_store: ResMut<GizmoConfigStore>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ShadowCatcherMaterial>>,
+) {
+    let (config, _) = config_store.config_mut::<OverlayGizmos>();
+    config.depth_bias = -1.0;
+    config.line.width = 3.0;
+
+    // Unit plane, scaled and moved under the model once it is loaded. The base material is only
+    // lit to find where the shadows are, the shader draws nothing but those.
+    commands.spawn((
+        GroundPlane,
+        Name::new("Ground plane"),
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(1.0, 1.0))),
+        MeshMaterial3d(materials.add(ShadowCatcherMaterial {
+            base: StandardMaterial {
+                perceptual_roughness: 1.0,
+                reflectance: 0.0,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            },
+            extension: ShadowCatcher { strength: 0.7 },
+        })),
+        Transform::default(),
+        Visibility::Hidden,
+        NotShadowCaster,
+        NoWireframe,
+        Pickable::IGNORE,
+    ));
+}
+
+pub fn display_shortcuts(keys: Res<ButtonInput<KeyCode>>, mut settings: ResMut<DisplaySettings>) {
+    if keys.just_pressed(KeyCode::KeyG) {
+        settings.grid = !settings.grid;
+    }
+    if keys.just_pressed(KeyCode::KeyB) {
+        settings.bounds = !settings.bounds;
+    }
+    if keys.just_pressed(KeyCode::KeyW) {
+        settings.wireframe = !settings.wireframe;
+    }
+    if keys.just_pressed(KeyCode::KeyV) {
+        settings.material_view = settings.material_view.next();
+    }
+}
+
+/// Draw the ground grid at y = 0 around the orbit camera's focus
+///
+/// The spacing follows the zoom level, so the grid reads the same at any model scale
+/// and always reaches past the edges of the view.
+pub fn draw_grid(
+    settings: Res<DisplaySettings>,
+    camera_query: Query<&PanOrbitCamera>,
+    mut gizmos: Gizmos,
+) {
+    if !settings.grid {
+        return;
+    }
+    let Ok(camera) = camera_query.single() else {
+        return;
+    };
+
+    let radius = camera.radius.unwrap_or(camera.target_radius).max(1e-4);
+    let major = 10.0_f32.powf(radius.log10().floor());
+    let minor = major * 0.1;
+
+    // Snap to the coarse grid so lines stay put while panning
+    let center = Vec3::new(
+        (camera.focus.x / major).round() * major,
+        0.0,
+        (camera.focus.z / major).round() * major,
+    );
+    // The grid is built in the XY plane
+    let isometry = Isometry3d::new(center, Quat::from_rotation_x(FRAC_PI_2));
+    let cells = UVec2::splat(GRID_CELLS);
+
+    gizmos.grid(
+        isometry,
+        cells,
+        Vec2::splat(minor),
+        Color::srgba(0.5, 0.5, 0.5, 0.12),
+    );
+    gizmos.grid(
+        isometry,
+        cells,
+        Vec2::splat(major),
+        Color::srgba(0.6, 0.6, 0.6, 0.3),
+    );
+
+    // World X and Z axes through the origin
+    let extent = major * GRID_CELLS as f32 * 0.5;
+    gizmos.line(
+        Vec3::new(center.x - extent, 0.0, 0.0),
+        Vec3::new(center.x + extent, 0.0, 0.0),
+        Color::srgba(0.9, 0.25, 0.25, 0.7),
+    );
+    gizmos.line(
+        Vec3::new(0.0, 0.0, center.z - extent),
+        Vec3::new(0.0, 0.0, center.z + extent),
+        Color::srgba(0.25, 0.45, 0.9, 0.7),
+    );
+}
+
+/// Keep the ground plane under the model's lowest point
+pub fn update_ground_plane(
+    settings: Res<DisplaySettings>,
+    viewer: Res<ModelViewer>,
+    children: Query<&Children>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform)>,
+    mut plane_query: Query<(&mut Transform, &mut Visibility), With<GroundPlane>>,
+) {
+    let bounds = viewer
+        .current_model
+        .filter(|_| settings.ground_plane)
+        .and_then(|model| model_bounds(model, &children, &mesh_bounds));
+
+    for (mut transform, mut visibility) in &mut plane_query {
+        let Some(bounds) = bounds else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Visible);
+
+        let center = Vec3::from(bounds.center());
+        let size = Vec3::from(bounds.half_size()).xz().max_element().max(0.01) * 6.0;
+        // Slightly below the model so the soles of the feet don't z-fight
+        let ground = Transform::from_xyz(center.x, bounds.min.y - size * 1e-4, center.z)
+            .with_scale(Vec3::new(size, 1.0, size));
+        transform.set_if_neq(ground);
+    }
+}
+
+/// Draw an XYZ orientation gizmo in the bottom right corner of the active camera's view
+pub fn draw_axis_gizmo(
+    settings: Res<DisplaySettings>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut gizmos: Gizmos<OverlayGizmos>,
+) {
+    if !settings.axis_gizmo {
+        return;
+    }
+    let Some((camera, camera_transform)) = cameras.iter().find(|(camera, _)| camera.is_active)
+    else {
+        return;
+    };
+    let Some(size) = camera.logical_viewport_size() else {
+        return;
+    };
+
+    // Place the gizmo just in front of the camera, sized in screen space
+    let corner = size - Vec2::splat(AXIS_GIZMO_INSET);
+    let (Ok(ray), Ok(side_ray)) = (
+        camera.viewport_to_world(camera_transform, corner),
+        camera.viewport_to_world(camera_transform, corner + Vec2::X * AXIS_GIZMO_SIZE),
+    ) else {
+        return;
+    };
+    let origin = ray.get_point(1.0);
+    let length = origin.distance(side_ray.get_point(1.0));
+
+    gizmos.arrow(
+        origin,
+        origin + Vec3::X * length,
+        Color::srgb(0.9, 0.25, 0.25),
+    );
+    gizmos.arrow(
+        origin,
+        origin + Vec3::Y * length,
+        Color::srgb(0.35, 0.8, 0.3),
+    );
+    gizmos.arrow(
+        origin,
+        origin + Vec3::Z * length,
+        Color::srgb(0.25, 0.45, 0.9),
+    );
+}
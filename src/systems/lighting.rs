@@ -0,0 +1,273 @@
+use bevy::light::{CascadeShadowConfig, CascadeShadowConfigBuilder};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy_file_dialog::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::camera::cursor_over_ui;
+use crate::components::{DraggablePanel, LightRigFile, RigLightEntity, ScrollArea};
+use crate::resources::{LightRig, RigLight, RigLightKind};
+
+/// Screen distance in logical pixels within which a press grabs a light's handle
+const HANDLE_GRAB_DISTANCE: f32 = 12.0;
+
+/// Keep the scene lights in sync with the rig
+///
+/// Lights are updated in place so dragging a slider doesn't recreate them every frame; they are
+/// only respawned when lights are added, removed or change kind.
+#[allow(clippy::type_complexity)]
+pub fn apply_light_rig(
+    mut commands: Commands,
+    rig: Res<LightRig>,
+    mut existing: Query<(
+        Entity,
+        &RigLightEntity,
+        &mut Transform,
+        Option<&mut DirectionalLight>,
+        Option<&mut PointLight>,
+        Option<&mut SpotLight>,
+        Option<&mut CascadeShadowConfig>,
+    )>,
+) {
+    if !rig.is_changed() {
+        return;
+    }
+
+    let cascades = cascade_config(&rig);
+    let same_lights = existing.iter().len() == rig.lights.len()
+        && existing
+            .iter()
+            .all(|(_, index, _, directional, point, spot, _)| {
+                let kind = match (directional, point, spot) {
+                    (Some(_), _, _) => RigLightKind::Directional,
+                    (_, Some(_), _) => RigLightKind::Point,
+                    (_, _, Some(_)) => RigLightKind::Spot,
+                    _ => return false,
+                };
+                rig.lights
+                    .get(index.0)
+                    .is_some_and(|light| light.kind == kind)
+            });
+
+    if same_lights {
+        for (_, index, mut transform, directional, point, spot, config) in &mut existing {
+            let light = &rig.lights[index.0];
+            *transform = light.transform();
+            if let Some(mut directional) = directional {
+                *directional = directional_light(light);
+            }
+            if let Some(mut point) = point {
+                *point = point_light(light);
+            }
+            if let Some(mut spot) = spot {
+                *spot = spot_light(light);
+            }
+            if let Some(mut config) = config {
+                *config = cascades.clone();
+            }
+        }
+        return;
+    }
+
+    for (entity, ..) in &existing {
+        commands.entity(entity).despawn();
+    }
+    for (i, light) in rig.lights.iter().enumerate() {
+        let name = Name::new(format!("{} light {}", light.kind.label(), i + 1));
+        let mut entity = commands.spawn((RigLightEntity(i), name, light.transform()));
+        match light.kind {
+            RigLightKind::Directional => {
+                entity.insert((directional_light(light), cascades.clone()));
+            }
+            RigLightKind::Point => {
+                entity.insert(point_light(light));
+            }
+            RigLightKind::Spot => {
+                entity.insert(spot_light(light));
+            }
+        }
+    }
+}
+
+fn cascade_config(rig: &LightRig) -> CascadeShadowConfig {
+    // The first cascade has to end before the last one does
+    let shadow_distance = rig.shadow_distance.max(2.0);
+    CascadeShadowConfigBuilder {
+        num_cascades: rig.cascades.clamp(1, 4),
+        maximum_distance: shadow_distance,
+        first_cascade_far_bound: shadow_distance * 0.1,
+        ..default()
+    }
+    .build()
+}
+
+fn directional_light(light: &RigLight) -> DirectionalLight {
+    DirectionalLight {
+        color: Color::from(light.color),
+        illuminance: light.intensity,
+        shadows_enabled: light.shadows,
+        ..default()
+    }
+}
+
+fn point_light(light: &RigLight) -> PointLight {
+    PointLight {
+        color: Color::from(light.color),
+        intensity: light.intensity,
+        range: (light.distance * 4.0).max(20.0),
+        shadows_enabled: light.shadows,
+        ..default()
+    }
+}
+
+fn spot_light(light: &RigLight) -> SpotLight {
+    SpotLight {
+        color: Color::from(light.color),
+        intensity: light.intensity,
+        range: (light.distance * 4.0).max(20.0),
+        shadows_enabled: light.shadows,
+        ..default()
+    }
+}
+
+/// Show where each light sits and where it points; the selected light is drawn brighter
+///
+/// The sphere at each light is the handle dragged by [`drag_light_handles`].
+pub fn draw_light_gizmos(mut gizmos: Gizmos, rig: Res<LightRig>) {
+    for (i, light) in rig.lights.iter().enumerate() {
+        let alpha = if i == rig.selected { 1.0 } else { 0.35 };
+        let color = light.color.with_alpha(alpha);
+        let position = light.position();
+        gizmos.sphere(position, light.distance * 0.03, color);
+        if light.kind != RigLightKind::Point {
+            gizmos.arrow(position, position * 0.5, color);
+        }
+    }
+}
+
+/// Drag a light's handle in the viewport to move it around the origin, which sets its direction
+///
+/// Pressing on a handle selects that light and holds the orbit camera until the button is released.
+#[allow(clippy::too_many_arguments)]
+pub fn drag_light_handles(
+    mut rig: ResMut<LightRig>,
+    mut dragging: Local<Option<usize>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    panel_query: Query<&Interaction, With<DraggablePanel>>,
+    scroll_query: Query<&RelativeCursorPosition, With<ScrollArea>>,
+    mut orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    if !mouse_button.pressed(MouseButton::Left) {
+        *dragging = None;
+        return;
+    }
+    let Some(cursor) = windows.single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Some((camera, camera_transform)) = cameras.iter().find(|(camera, _)| camera.is_active)
+    else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Left) && !cursor_over_ui(&panel_query, &scroll_query)
+    {
+        *dragging = rig
+            .lights
+            .iter()
+            .enumerate()
+            .filter_map(|(i, light)| {
+                let screen = camera
+                    .world_to_viewport(camera_transform, light.position())
+                    .ok()?;
+                Some((i, screen.distance(cursor)))
+            })
+            .filter(|(_, distance)| *distance <= HANDLE_GRAB_DISTANCE)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(i, _)| i);
+        if let Some(index) = *dragging
+            && rig.selected != index
+        {
+            rig.selected = index;
+        }
+    }
+    let Some((index, light)) = dragging.and_then(|index| Some((index, rig.lights.get(index)?)))
+    else {
+        return;
+    };
+
+    for mut orbit in &mut orbit_query {
+        orbit.enabled = false;
+    }
+
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+    let position = point_on_sphere(ray, light.position(), light.distance);
+    let yaw = position.x.atan2(position.z).to_degrees().rem_euclid(360.0);
+    let pitch = (position.y / light.distance)
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees();
+    if (yaw, pitch) != (light.yaw, light.pitch) {
+        let light = &mut rig.lights[index];
+        light.yaw = yaw;
+        light.pitch = pitch;
+    }
+}
+
+/// Where `ray` meets the sphere of `radius` around the origin, taking the side nearer to
+/// `previous` so a handle behind the model doesn't jump to the front. A ray missing the sphere
+/// gives its closest point instead.
+fn point_on_sphere(ray: Ray3d, previous: Vec3, radius: f32) -> Vec3 {
+    let b = ray.origin.dot(*ray.direction);
+    let c = ray.origin.length_squared() - radius * radius;
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return ray.get_point(-b).normalize_or(previous) * radius;
+    }
+    let root = discriminant.sqrt();
+    [-b - root, -b + root]
+        .into_iter()
+        .filter(|t| *t >= 0.0)
+        .map(|t| ray.get_point(t))
+        .min_by(|a, b| a.distance(previous).total_cmp(&b.distance(previous)))
+        .unwrap_or(previous)
+}
+
+pub fn handle_light_rig_files(
+    mut loaded: MessageReader<DialogFileLoaded<LightRigFile>>,
+    mut saved: MessageReader<DialogFileSaved<LightRigFile>>,
+    mut rig: ResMut<LightRig>,
+) {
+    for event in loaded.read() {
+        let name = rig_name(&event.file_name);
+        let text = String::from_utf8_lossy(&event.contents);
+        match LightRig::parse(&name, &text) {
+            Ok(loaded_rig) => {
+                info!("Loaded lighting rig {}", name);
+                *rig = loaded_rig;
+            }
+            Err(err) => warn!("Invalid lighting rig {}: {}", event.file_name, err),
+        }
+    }
+
+    for event in saved.read() {
+        match &event.result {
+            Ok(()) => {
+                info!("Saved lighting rig {}", event.file_name);
+                rig.name = rig_name(&event.file_name);
+            }
+            Err(err) => warn!("Failed to save lighting rig {}: {}", event.file_name, err),
+        }
+    }
+}
+
+/// Rig name from a file name like `studio.rig`
+fn rig_name(file_name: &str) -> String {
+    file_name
+        .strip_suffix(".rig")
+        .unwrap_or(file_name)
+        .to_string()
+}
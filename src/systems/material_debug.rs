@@ -0,0 +1,378 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::image::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor};
+use bevy::math::Affine2;
+use bevy::pbr::UvChannel;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::OriginalMaterial;
+use crate::resources::{DisplaySettings, MaterialVariants, MaterialView, ModelViewer};
+
+/// Size of the generated UV gradient texture
+const UV_GRADIENT_SIZE: u32 = 256;
+
+/// Size of the generated checker texture
+const CHECKER_SIZE: u32 = 1024;
+
+/// Checker squares along each side of the texture
+const CHECKER_CELLS: u32 = 16;
+
+/// Normal map color of a surface facing straight out, shown when there is no normal map
+const FLAT_NORMAL_COLOR: Color = Color::srgb(0.5, 0.5, 1.0);
+
+/// Texture channels a debug view can isolate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Channel {
+    Red,
+    Green,
+    Blue,
+    All,
+}
+
+/// Textures generated for the debug views of the current model
+#[derive(Default)]
+pub struct DebugTextures {
+    model: Option<Entity>,
+    /// Single channel copies by source image, channel and factor, `None` if unreadable
+    channels: HashMap<(AssetId<Image>, Channel, u32), Option<Handle<Image>>>,
+    uv_gradient: Option<Handle<Image>>,
+    checker: Option<Handle<Image>>,
+}
+
+impl DebugTextures {
+    fn channel(
+        &mut self,
+        texture: &Handle<Image>,
+        channel: Channel,
+        factor: f32,
+        images: &mut Assets<Image>,
+    ) -> Option<Handle<Image>> {
+        self.channels
+            .entry((texture.id(), channel, factor.to_bits()))
+            .or_insert_with(|| {
+                let image = extract_channel(images.get(texture)?, channel, factor)?;
+                Some(images.add(image))
+            })
+            .clone()
+    }
+
+    fn uv_gradient(&mut self, images: &mut Assets<Image>) -> Handle<Image> {
+        self.uv_gradient
+            .get_or_insert_with(|| images.add(uv_gradient()))
+            .clone()
+    }
+
+    fn checker(&mut self, images: &mut Assets<Image>) -> Handle<Image> {
+        self.checker
+            .get_or_insert_with(|| images.add(checker()))
+            .clone()
+    }
+}
+
+/// Swap the model's materials for debug materials showing a single channel or a UV checker, and back
+///
+/// Runs when the view changes, when new meshes spawn, e.g. after switching scenes, and when a
+/// material variant replaces the authored materials underneath the debug ones.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_material_view(
+    mut commands: Commands,
+    settings: Res<DisplaySettings>,
+    variants: Res<MaterialVariants>,
+    viewer: Res<ModelViewer>,
+    meshes: Res<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    children: Query<&Children>,
+    added: Query<(), Added<MeshMaterial3d<StandardMaterial>>>,
+    mut mesh_query: Query<(
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&OriginalMaterial>,
+        Option<&Mesh3d>,
+    )>,
+    mut cameras: Query<&mut Tonemapping, With<Camera3d>>,
+    mut applied: Local<MaterialView>,
+    mut textures: Local<DebugTextures>,
+) {
+    let view = settings.material_view;
+    if view == *applied && added.is_empty() && !variants.is_changed() {
+        return;
+    }
+    *applied = view;
+
+    // Tonemapping would shift the unlit debug colors away from the stored values
+    let tonemapping = match view {
+        MaterialView::Lit | MaterialView::CheckerUv0 | MaterialView::CheckerUv1 => {
+            Tonemapping::default()
+        }
+        _ => Tonemapping::None,
+    };
+    for mut camera_tonemapping in &mut cameras {
+        camera_tonemapping.set_if_neq(tonemapping);
+    }
+
+    let Some(model) = viewer.current_model else {
+        return;
+    };
+    if textures.model != Some(model) {
+        *textures = DebugTextures {
+            model: Some(model),
+            ..default()
+        };
+    }
+
+    let uv_attribute = match view.uv_channel() {
+        UvChannel::Uv0 => Mesh::ATTRIBUTE_UV_0,
+        UvChannel::Uv1 => Mesh::ATTRIBUTE_UV_1,
+    };
+    // Nodes sharing a material share its debug material too
+    let mut debug_materials = HashMap::new();
+
+    for entity in children.iter_descendants(model) {
+        let Ok((mut material, original, mesh3d)) = mesh_query.get_mut(entity) else {
+            continue;
+        };
+        let original = original.map_or_else(|| material.0.clone(), |original| original.0.clone());
+
+        if view == MaterialView::Lit {
+            if material.0 != original {
+                material.0 = original;
+            }
+            commands.entity(entity).remove::<OriginalMaterial>();
+            continue;
+        }
+
+        let Some(source) = materials.get(&original).cloned() else {
+            continue;
+        };
+        let has_uvs = mesh3d
+            .and_then(|mesh3d| meshes.get(&mesh3d.0))
+            .is_some_and(|mesh| mesh.contains_attribute(uv_attribute));
+        let handle = debug_materials
+            .entry((original.id(), has_uvs))
+            .or_insert_with(|| {
+                let debug = debug_material(view, &source, has_uvs, &mut images, &mut textures);
+                materials.add(debug)
+            })
+            .clone();
+
+        material.0 = handle;
+        commands.entity(entity).insert(OriginalMaterial(original));
+    }
+}
+
+/// Material showing the channel of `source` selected by `view`
+fn debug_material(
+    view: MaterialView,
+    source: &StandardMaterial,
+    has_uvs: bool,
+    images: &mut Assets<Image>,
+    textures: &mut DebugTextures,
+) -> StandardMaterial {
+    let mut material = StandardMaterial {
+        unlit: true,
+        double_sided: source.double_sided,
+        cull_mode: source.cull_mode,
+        uv_transform: source.uv_transform,
+        ..default()
+    };
+
+    match view {
+        MaterialView::Lit => return source.clone(),
+        MaterialView::BaseColor => {
+            material.base_color = source.base_color;
+            material.base_color_texture = source.base_color_texture.clone();
+            material.base_color_channel = source.base_color_channel.clone();
+            material.alpha_mode = source.alpha_mode;
+        }
+        // glTF packs metallic into blue and roughness into green
+        MaterialView::Metallic => show_channel(
+            &mut material,
+            &source.metallic_roughness_texture,
+            &source.metallic_roughness_channel,
+            Channel::Blue,
+            source.metallic,
+            images,
+            textures,
+        ),
+        MaterialView::Roughness => show_channel(
+            &mut material,
+            &source.metallic_roughness_texture,
+            &source.metallic_roughness_channel,
+            Channel::Green,
+            source.perceptual_roughness,
+            images,
+            textures,
+        ),
+        MaterialView::NormalMap if source.normal_map_texture.is_none() => {
+            material.base_color = FLAT_NORMAL_COLOR;
+        }
+        MaterialView::NormalMap => show_channel(
+            &mut material,
+            &source.normal_map_texture,
+            &source.normal_map_channel,
+            Channel::All,
+            1.0,
+            images,
+            textures,
+        ),
+        MaterialView::Occlusion => show_channel(
+            &mut material,
+            &source.occlusion_texture,
+            &source.occlusion_channel,
+            Channel::Red,
+            1.0,
+            images,
+            textures,
+        ),
+        MaterialView::Emissive => {
+            material.base_color = Color::from(source.emissive);
+            material.base_color_texture = source.emissive_texture.clone();
+            material.base_color_channel = source.emissive_channel.clone();
+        }
+        // Meshes without the UV set stay black
+        MaterialView::Uv0 | MaterialView::Uv1 if !has_uvs => {
+            material.base_color = Color::BLACK;
+        }
+        MaterialView::Uv0 | MaterialView::Uv1 => {
+            material.base_color_texture = Some(textures.uv_gradient(images));
+            material.base_color_channel = view.uv_channel();
+            material.uv_transform = Affine2::IDENTITY;
+        }
+        // The checker keeps the rest of the material and its lighting, so seams show in context
+        MaterialView::CheckerUv0 | MaterialView::CheckerUv1 => {
+            let mut checkered = StandardMaterial {
+                base_color: Color::WHITE,
+                base_color_texture: Some(textures.checker(images)),
+                base_color_channel: view.uv_channel(),
+                uv_transform: Affine2::IDENTITY,
+                ..source.clone()
+            };
+            if !has_uvs {
+                checkered.base_color = Color::BLACK;
+                checkered.base_color_texture = None;
+            }
+            return checkered;
+        }
+    }
+    material
+}
+
+/// Show one channel of `texture` as grayscale, scaled by `factor`
+///
+/// Falls back to the texture as is when its pixels can't be read, e.g. for compressed formats.
+fn show_channel(
+    material: &mut StandardMaterial,
+    texture: &Option<Handle<Image>>,
+    uv_channel: &UvChannel,
+    channel: Channel,
+    factor: f32,
+    images: &mut Assets<Image>,
+    textures: &mut DebugTextures,
+) {
+    let Some(texture) = texture else {
+        // Without a texture the factor applies everywhere
+        material.base_color = Color::srgb(factor, factor, factor);
+        return;
+    };
+    let extracted = textures.channel(texture, channel, factor, images);
+    material.base_color_texture = Some(extracted.unwrap_or_else(|| texture.clone()));
+    material.base_color_channel = uv_channel.clone();
+}
+
+/// Copy of `image` holding the stored values of `channel` times `factor`
+///
+/// The copy is sRGB, which the unlit shader and the output transfer cancel out,
+/// so the stored values are what ends up on screen.
+fn extract_channel(image: &Image, channel: Channel, factor: f32) -> Option<Image> {
+    let (width, height) = (image.width(), image.height());
+    let is_srgb = image.texture_descriptor.format.is_srgb();
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+
+    for y in 0..height {
+        for x in 0..width {
+            let color = image.get_color_at(x, y).ok()?;
+            // Undo the decoding implied by the texture format
+            let [r, g, b] = if is_srgb {
+                let color = color.to_srgba();
+                [color.red, color.green, color.blue]
+            } else {
+                let color = color.to_linear();
+                [color.red, color.green, color.blue]
+            };
+            let rgb = match channel {
+                Channel::Red => [r; 3],
+                Channel::Green => [g; 3],
+                Channel::Blue => [b; 3],
+                Channel::All => [r, g, b],
+            };
+            data.extend(rgb.map(|value| ((value * factor).clamp(0.0, 1.0) * 255.0).round() as u8));
+            data.push(u8::MAX);
+        }
+    }
+
+    let mut extracted = Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    extracted.sampler = image.sampler.clone();
+    Some(extracted)
+}
+
+/// Red rising with U and green with V, repeating outside 0..1
+fn uv_gradient() -> Image {
+    let size = UV_GRADIENT_SIZE;
+    let to_byte = |i: u32| ((i as f32 + 0.5) / size as f32 * 255.0) as u8;
+    let data = (0..size)
+        .flat_map(|y| (0..size).flat_map(move |x| [to_byte(x), to_byte(y), 0, u8::MAX]))
+        .collect();
+    repeating_texture(size, data)
+}
+
+/// Checkerboard whose light squares get redder along U and greener along V,
+/// so flipped or rotated islands stand out
+fn checker() -> Image {
+    let cell_size = CHECKER_SIZE / CHECKER_CELLS;
+    let tint = |cell: u32| 0.3 + 0.7 * cell as f32 / (CHECKER_CELLS - 1) as f32;
+    let data = (0..CHECKER_SIZE)
+        .flat_map(|y| {
+            (0..CHECKER_SIZE).flat_map(move |x| {
+                let (cell_x, cell_y) = (x / cell_size, y / cell_size);
+                let light = [tint(cell_x), tint(cell_y), 0.85];
+                let brightness = if (cell_x + cell_y) % 2 == 0 { 1.0 } else { 0.2 };
+                let [r, g, b] = light.map(|value| (value * brightness * 255.0) as u8);
+                [r, g, b, u8::MAX]
+            })
+        })
+        .collect();
+    repeating_texture(CHECKER_SIZE, data)
+}
+
+/// Square sRGB texture that tiles outside 0..1
+fn repeating_texture(size: u32, data: Vec<u8>) -> Image {
+    let mut image = Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        address_mode_u: ImageAddressMode::Repeat,
+        address_mode_v: ImageAddressMode::Repeat,
+        ..ImageSamplerDescriptor::linear()
+    });
+    image
+}
@@ -0,0 +1,185 @@
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+
+use super::bounds::aabb_box;
+use super::guides::OverlayGizmos;
+use crate::components::OriginalMaterial;
+use crate::resources::{MaterialBrowser, MaterialEntry, ModelViewer, TextureEntry};
+
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+
+/// Fill the material browser from the loaded glTF asset
+pub fn collect_materials(
+    viewer: Res<ModelViewer>,
+    asset_server: Res<AssetServer>,
+    mut browser: ResMut<MaterialBrowser>,
+    gltf_assets: Res<Assets<Gltf>>,
+    materials: Res<Assets<StandardMaterial>>,
+    images: Res<Assets<Image>>,
+) {
+    let Some(gltf_handle) = &viewer.gltf_handle else {
+        return;
+    };
+    if browser.source == Some(gltf_handle.id()) {
+        return;
+    }
+    // Wait for external textures so their sizes are known; ones that fail are skipped
+    if asset_server
+        .recursive_dependency_load_state(gltf_handle)
+        .is_loading()
+    {
+        return;
+    }
+    let Some(gltf) = gltf_assets.get(gltf_handle) else {
+        return;
+    };
+
+    let mut new_browser = MaterialBrowser {
+        source: Some(gltf_handle.id()),
+        ..default()
+    };
+
+    for (i, handle) in gltf.materials.iter().enumerate() {
+        let Some(material) = materials.get(handle) else {
+            continue;
+        };
+        let name = gltf
+            .named_materials
+            .iter()
+            .find(|(_, named)| *named == handle)
+            .map_or_else(|| format!("Material {i}"), |(name, _)| name.to_string());
+
+        let slots = [
+            ("base color", &material.base_color_texture),
+            ("metal/rough", &material.metallic_roughness_texture),
+            ("normal", &material.normal_map_texture),
+            ("occlusion", &material.occlusion_texture),
+            ("emissive", &material.emissive_texture),
+        ];
+        for (slot, texture) in slots {
+            let Some(texture) = texture else {
+                continue;
+            };
+            let used_by = format!("{name} ({slot})");
+            if let Some(entry) = new_browser
+                .textures
+                .iter_mut()
+                .find(|entry| entry.handle == *texture)
+            {
+                entry.used_by.push(used_by);
+                continue;
+            }
+            let Some(image) = images.get(texture) else {
+                continue;
+            };
+            new_browser.textures.push(TextureEntry {
+                label: texture_label(gltf, texture),
+                handle: texture.clone(),
+                width: image.width(),
+                height: image.height(),
+                format: format!("{:?}", image.texture_descriptor.format),
+                is_srgb: image.texture_descriptor.format.is_srgb(),
+                used_by: vec![used_by],
+            });
+        }
+
+        new_browser.materials.push(MaterialEntry {
+            name,
+            handle: handle.clone(),
+            summary: material_summary(material),
+        });
+    }
+
+    *browser = new_browser;
+}
+
+/// Image name from the glTF document, or the asset label when the image has none
+fn texture_label(gltf: &Gltf, texture: &Handle<Image>) -> String {
+    let label = texture
+        .path()
+        .and_then(|path| path.label())
+        .unwrap_or("Texture");
+    // Texture sub-assets are labeled by their index in the document
+    let name = label
+        .strip_prefix("Texture")
+        .and_then(|index| index.parse::<usize>().ok())
+        .zip(gltf.source.as_ref())
+        .and_then(|(index, document)| document.textures().nth(index))
+        .and_then(|texture| {
+            texture
+                .source()
+                .name()
+                .or_else(|| texture.name())
+                .map(str::to_string)
+        });
+    name.unwrap_or_else(|| label.to_string())
+}
+
+fn material_summary(material: &StandardMaterial) -> String {
+    let mut summary = format!(
+        "base {}  metal {:.2}  rough {:.2}",
+        material.base_color.to_srgba().to_hex(),
+        material.metallic,
+        material.perceptual_roughness
+    );
+    match material.alpha_mode {
+        AlphaMode::Opaque => {}
+        AlphaMode::Mask(cutoff) => summary.push_str(&format!("  mask {cutoff:.2}")),
+        AlphaMode::Blend => summary.push_str("  blend"),
+        _ => summary.push_str("  alpha"),
+    }
+    if material.emissive != LinearRgba::BLACK {
+        summary.push_str("  emissive");
+    }
+    if material.double_sided {
+        summary.push_str("  2-sided");
+    }
+    if material.unlit {
+        summary.push_str("  unlit");
+    }
+    summary
+}
+
+/// Outline the meshes using the material selected in the browser
+///
+/// Meshes under a mirrored transform use an `(inverted)` copy of the material, so materials
+/// are matched by their glTF index.
+pub fn draw_material_highlight(
+    browser: Res<MaterialBrowser>,
+    viewer: Res<ModelViewer>,
+    children: Query<&Children>,
+    mesh_query: Query<(
+        &MeshMaterial3d<StandardMaterial>,
+        Option<&OriginalMaterial>,
+        &Aabb,
+        &GlobalTransform,
+    )>,
+    mut gizmos: Gizmos<OverlayGizmos>,
+) {
+    let (Some(selected), Some(model)) = (browser.selected_material(), viewer.current_model) else {
+        return;
+    };
+    let Some(selected_index) = material_index(&selected.handle) else {
+        return;
+    };
+
+    for entity in children.iter_descendants(model) {
+        let Ok((material, original, aabb, transform)) = mesh_query.get(entity) else {
+            continue;
+        };
+        // Debug views swap materials, so compare against the authored one
+        let authored = original.map_or(&material.0, |original| &original.0);
+        if material_index(authored) != Some(selected_index) {
+            continue;
+        }
+        gizmos.cuboid(aabb_box(aabb, transform), HIGHLIGHT_COLOR);
+    }
+}
+
+/// glTF material index of a material loaded from the `Material{n}` sub-asset or its
+/// `Material{n} (inverted)` copy
+fn material_index(material: &Handle<StandardMaterial>) -> Option<usize> {
+    let label = material.path()?.label()?;
+    let label = label.strip_suffix(" (inverted)").unwrap_or(label);
+    label.strip_prefix("Material")?.parse().ok()
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use super::guides::OverlayGizmos;
+use crate::components::MeasurementLabel;
+use crate::resources::Measurements;
+
+/// Screen distance in logical pixels within which points snap to a triangle corner
+const SNAP_DISTANCE: f32 = 12.0;
+
+/// Size of the measurement end points relative to their distance from the camera
+const POINT_SCALE: f32 = 0.006;
+
+const MEASURE_COLOR: Color = Color::srgb(1.0, 0.55, 0.2);
+
+pub fn measure_shortcuts(keys: Res<ButtonInput<KeyCode>>, mut measurements: ResMut<Measurements>) {
+    if keys.just_pressed(KeyCode::KeyM) {
+        measurements.active = !measurements.active;
+    }
+    if keys.just_pressed(KeyCode::Escape) && measurements.pending.is_some() {
+        measurements.pending = None;
+    }
+}
+
+/// Corner of `triangle` closest to the cursor on screen, if it is within snapping distance
+pub fn snap_to_vertex(
+    triangle: [Vec3; 3],
+    cursor: Vec2,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Vec3> {
+    triangle
+        .into_iter()
+        .filter_map(|vertex| {
+            let screen = camera.world_to_viewport(camera_transform, vertex).ok()?;
+            Some((vertex, screen.distance(cursor)))
+        })
+        .filter(|(_, distance)| *distance <= SNAP_DISTANCE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(vertex, _)| vertex)
+}
+
+/// Keep one distance label per finished measurement
+pub fn update_measurement_labels(
+    mut commands: Commands,
+    measurements: Res<Measurements>,
+    labels: Query<Entity, With<MeasurementLabel>>,
+) {
+    if !measurements.is_changed() {
+        return;
+    }
+
+    for entity in &labels {
+        commands.entity(entity).despawn();
+    }
+    for (i, measurement) in measurements.completed.iter().enumerate() {
+        commands.spawn((
+            MeasurementLabel(i),
+            Text::new(format!("{:.3} m", measurement.distance())),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(MEASURE_COLOR),
+            // Positioned by `draw_measurements` once it has a camera to project with
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.75)),
+            BorderRadius::all(Val::Px(3.0)),
+            GlobalZIndex(9),
+            // Clicks go through to the model underneath
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+/// Draw the measurements as lines between their end points, with the distance at the middle
+pub fn draw_measurements(
+    measurements: Res<Measurements>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut labels: Query<(&MeasurementLabel, &mut Node)>,
+    mut gizmos: Gizmos<OverlayGizmos>,
+) {
+    let Some((camera, camera_transform)) = cameras.iter().find(|(camera, _)| camera.is_active)
+    else {
+        return;
+    };
+    let points = measurements
+        .completed
+        .iter()
+        .flat_map(|measurement| [measurement.start, measurement.end])
+        .chain(measurements.pending);
+    for point in points {
+        // Sized by distance so the points look the same at any zoom level
+        let radius = camera_transform.translation().distance(point) * POINT_SCALE;
+        gizmos.sphere(Isometry3d::from_translation(point), radius, MEASURE_COLOR);
+    }
+    for measurement in &measurements.completed {
+        gizmos.line(measurement.start, measurement.end, MEASURE_COLOR);
+    }
+
+    for (label, mut node) in &mut labels {
+        let position = measurements.completed.get(label.0).and_then(|measurement| {
+            let middle = measurement.start.lerp(measurement.end, 0.5);
+            camera.world_to_viewport(camera_transform, middle).ok()
+        });
+        let Some(position) = position else {
+            node.display = Display::None;
+            continue;
+        };
+        node.display = Display::Flex;
+        node.left = Val::Px(position.x + 4.0);
+        node.top = Val::Px(position.y - 8.0);
+    }
+}
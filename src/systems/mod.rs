@@ -1,9 +1,48 @@
 mod animation;
+mod bounds;
 mod camera;
+mod environment;
+mod guides;
+mod lighting;
+mod material_debug;
+mod materials;
+mod measure;
 mod model;
+mod morph;
 mod panel;
+mod picking;
+mod render_debug;
+mod skeleton;
+mod startup;
+mod stats;
+mod variants;
 
-pub use animation::{control_animations, setup_animations};
-pub use camera::disable_camera_on_ui_hover;
-pub use model::handle_loaded_model;
-pub use panel::{drag_panel, scroll_animation_list};
+pub use animation::{control_animations, playback_shortcuts, setup_animations};
+pub use bounds::draw_bounds;
+pub use camera::{
+    CameraPreset, camera_shortcuts, disable_camera_on_ui_hover, frame_model, reframe_model,
+    scene_cameras, sync_active_camera, toggle_projection,
+};
+pub use environment::{
+    apply_environment, handle_environment_file, prepare_environment, setup_default_environment,
+    use_default_environment,
+};
+pub use guides::{
+    OverlayGizmos, ShadowCatcherPlugin, display_shortcuts, draw_axis_gizmo, draw_grid,
+    setup_guides, update_ground_plane,
+};
+pub use lighting::{
+    apply_light_rig, drag_light_handles, draw_light_gizmos, handle_light_rig_files,
+};
+pub use material_debug::apply_material_view;
+pub use materials::{collect_materials, draw_material_highlight};
+pub use measure::{draw_measurements, measure_shortcuts, update_measurement_labels};
+pub use model::{handle_loaded_model, load_model, spawn_model_scene, switch_scene};
+pub use morph::{collect_morph_targets, sync_morph_weights};
+pub use panel::{drag_panel, scroll_panels};
+pub use picking::{draw_selection_highlight, pick_mesh};
+pub use render_debug::{apply_shading_mode, apply_wireframe, draw_vertex_vectors};
+pub use skeleton::{SkeletonGizmos, draw_skeleton, setup_skeleton_gizmos};
+pub use startup::apply_cli_args;
+pub use stats::compute_model_stats;
+pub use variants::{apply_material_variant, collect_variants};
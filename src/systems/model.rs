@@ -1,8 +1,10 @@
+use bevy::gltf::GltfLoaderSettings;
 use bevy::prelude::*;
 use bevy_file_dialog::prelude::*;
+use std::path::PathBuf;
 
 use crate::components::GltfModelFile;
-use crate::resources::ModelViewer;
+use crate::resources::{LoadOptions, ModelViewer, SceneSelector};
 
 pub fn handle_loaded_model(
     mut ev: MessageReader<DialogFileLoaded<GltfModelFile>>,
@@ -11,33 +13,135 @@ pub fn handle_loaded_model(
     mut viewer: ResMut<ModelViewer>,
 ) {
     for event in ev.read() {
-        // Despawn previous model
-        if let Some(entity) = viewer.current_model {
-            commands.entity(entity).despawn();
+        load_model(
+            &mut commands,
+            &asset_server,
+            &mut viewer,
+            event.path.clone(),
+            LoadOptions::default(),
+        );
+    }
+}
+
+/// Replace the current model with the glTF file at `path`
+///
+/// The scene itself is spawned by [`spawn_model_scene`] once the glTF asset has loaded.
+pub fn load_model(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    viewer: &mut ModelViewer,
+    path: PathBuf,
+    options: LoadOptions,
+) {
+    despawn_model(commands, viewer);
+    viewer.gltf_handle = None;
+    viewer.scenes.clear();
+    viewer.current_scene = 0;
+    viewer.load_options = options;
+
+    // Load the GLTF asset and store the handle for scene and animation loading.
+    // The parsed glTF document is kept for data Bevy does not expose, like keyframe counts.
+    let gltf_handle: Handle<Gltf> =
+        asset_server.load_with_settings(path.clone(), |settings: &mut GltfLoaderSettings| {
+            settings.include_source = true;
+        });
+    viewer.gltf_handle = Some(gltf_handle);
+    viewer.model_path = Some(path);
+
+    let entity = commands.spawn(Transform::default()).id();
+    viewer.current_model = Some(entity);
+}
+
+/// Show another scene of the loaded glTF file without reloading it
+///
+/// Animations are set up again for the new hierarchy by `setup_animations`.
+pub fn switch_scene(commands: &mut Commands, viewer: &mut ModelViewer, index: usize) {
+    if viewer.gltf_handle.is_none() || index == viewer.current_scene {
+        return;
+    }
+
+    despawn_model(commands, viewer);
+    viewer.load_options.scene = Some(SceneSelector::Index(index));
+
+    let entity = commands.spawn(Transform::default()).id();
+    viewer.current_model = Some(entity);
+}
+
+/// Despawn the model hierarchy and reset the state tied to it
+fn despawn_model(commands: &mut Commands, viewer: &mut ModelViewer) {
+    if let Some(entity) = viewer.current_model.take() {
+        commands.entity(entity).despawn();
+    }
+
+    viewer.animations.clear();
+    viewer.graph_handle = None;
+    viewer.current_animation = 0;
+    viewer.is_playing = false;
+}
+
+pub fn spawn_model_scene(
+    mut commands: Commands,
+    mut viewer: ResMut<ModelViewer>,
+    gltf_assets: Res<Assets<Gltf>>,
+    scene_roots: Query<(), With<SceneRoot>>,
+) {
+    let Some(model_entity) = viewer.current_model else {
+        return;
+    };
+    if scene_roots.contains(model_entity) {
+        return;
+    }
+    let Some(gltf) = viewer
+        .gltf_handle
+        .as_ref()
+        .and_then(|handle| gltf_assets.get(handle))
+    else {
+        return;
+    };
+
+    let requested = viewer.load_options.scene.take();
+    let index = match &requested {
+        Some(SceneSelector::Index(index)) => {
+            Some(*index).filter(|index| *index < gltf.scenes.len())
         }
+        Some(SceneSelector::Name(name)) => gltf
+            .named_scenes
+            .get(name.as_str())
+            .and_then(|handle| scene_index(gltf, handle)),
+        None => None,
+    };
+    if index.is_none() && requested.is_some() {
+        warn!("Scene {:?} not found, using the default scene", requested);
+    }
+
+    // Files without a default scene fall back to the first one
+    let index = index.unwrap_or_else(|| {
+        gltf.default_scene
+            .as_ref()
+            .and_then(|handle| scene_index(gltf, handle))
+            .unwrap_or(0)
+    });
 
-        // Reset viewer state
-        viewer.animations.clear();
-        viewer.animation_names.clear();
-        viewer.graph_handle = None;
-        viewer.gltf_handle = None;
-        viewer.current_animation = 0;
-        viewer.is_playing = false;
-
-        // Load new model
-        let path = event.path.clone();
-        viewer.model_path = Some(path.clone());
-
-        // Load the GLTF asset and store the handle for animation loading
-        let gltf_handle: Handle<Gltf> = asset_server.load(path.clone());
-        viewer.gltf_handle = Some(gltf_handle.clone());
-
-        // Load the scene from the GLTF
-        let scene = asset_server.load(GltfAssetLabel::Scene(0).from_asset(path.clone()));
-        let entity = commands
-            .spawn((SceneRoot(scene), Transform::default()))
-            .id();
-
-        viewer.current_model = Some(entity);
+    // Files without any scene have nothing to show
+    let Some(scene) = gltf.scenes.get(index).cloned() else {
+        return;
+    };
+
+    // Resolve names by scene index, matching on the scene handle
+    let mut scenes: Vec<String> = (0..gltf.scenes.len())
+        .map(|i| format!("Scene {}", i + 1))
+        .collect();
+    for (name, handle) in gltf.named_scenes.iter() {
+        if let Some(i) = scene_index(gltf, handle) {
+            scenes[i] = name.to_string();
+        }
     }
+
+    viewer.scenes = scenes;
+    viewer.current_scene = index;
+    commands.entity(model_entity).insert(SceneRoot(scene));
+}
+
+fn scene_index(gltf: &Gltf, handle: &Handle<Scene>) -> Option<usize> {
+    gltf.scenes.iter().position(|scene| scene == handle)
 }
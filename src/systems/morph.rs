@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::components::{MorphTargetSlider, Slider};
+use crate::resources::{ModelViewer, MorphMesh, MorphTargets};
+
+/// List the model's nodes with morph targets, when the model changes or its scene spawns
+pub fn collect_morph_targets(
+    viewer: Res<ModelViewer>,
+    mut morph: ResMut<MorphTargets>,
+    meshes: Res<Assets<Mesh>>,
+    children: Query<&Children>,
+    added: Query<(), Added<MorphWeights>>,
+    weights_query: Query<(&MorphWeights, Option<&Name>)>,
+) {
+    if morph.model == viewer.current_model && added.is_empty() {
+        return;
+    }
+
+    let mut morph_meshes = Vec::new();
+    if let Some(model) = viewer.current_model {
+        for entity in children.iter_descendants(model) {
+            let Ok((weights, name)) = weights_query.get(entity) else {
+                continue;
+            };
+            let target_names = weights
+                .first_mesh()
+                .and_then(|handle| meshes.get(handle))
+                .and_then(|mesh| mesh.morph_target_names());
+            let targets = (0..weights.weights().len())
+                .map(|i| {
+                    target_names
+                        .and_then(|names| names.get(i))
+                        .cloned()
+                        .unwrap_or_else(|| format!("Target {i}"))
+                })
+                .collect();
+            morph_meshes.push(MorphMesh {
+                entity,
+                name: name.map_or_else(|| format!("Node {entity}"), |name| name.to_string()),
+                targets,
+            });
+        }
+    }
+
+    morph.model = viewer.current_model;
+    morph.meshes = morph_meshes;
+}
+
+/// Sync the morph target sliders with the weights
+///
+/// Runs after animations are applied, so the sliders either follow the playing clip or win
+/// over it, depending on `MorphTargets::animation_override`. Sliders only win once they have
+/// been dragged, so weights set by the file or other tools are kept until then.
+pub fn sync_morph_weights(
+    morph: Res<MorphTargets>,
+    mut weights_query: Query<&mut MorphWeights>,
+    mut sliders: Query<(&mut Slider, &MorphTargetSlider)>,
+) {
+    for (mut slider, target) in &mut sliders {
+        let Ok(mut weights) = weights_query.get_mut(target.entity) else {
+            continue;
+        };
+        let Some(&weight) = weights.weights().get(target.index) else {
+            continue;
+        };
+        if morph.animation_override {
+            let value = weight.clamp(0.0, 1.0);
+            if (slider.value - value).abs() > 1e-4 {
+                slider.value = value;
+            }
+        } else if target.dragged && weight != slider.value {
+            weights.weights_mut()[target.index] = slider.value;
+        }
+    }
+}
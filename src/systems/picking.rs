@@ -0,0 +1,143 @@
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::bounds::aabb_box;
+use super::camera::cursor_over_ui;
+use super::guides::OverlayGizmos;
+use super::measure::snap_to_vertex;
+use crate::components::{DraggablePanel, ScrollArea};
+use crate::resources::{Measurements, ModelViewer, PickHit, Selection};
+
+/// Cursor travel in logical pixels up to which a press and release still count as a click,
+/// so releasing an orbit drag over the model doesn't change the selection
+const CLICK_TOLERANCE: f32 = 4.0;
+
+const SELECTION_COLOR: Color = Color::srgb(0.3, 0.9, 1.0);
+
+/// Select the model mesh clicked in the viewport, or clear the selection when clicking past it
+///
+/// In measure mode the click places a measurement point on the mesh instead.
+#[allow(clippy::too_many_arguments)]
+pub fn pick_mesh(
+    mut presses: MessageReader<Pointer<Press>>,
+    mut clicks: MessageReader<Pointer<Click>>,
+    mut press_position: Local<Option<Vec2>>,
+    viewer: Res<ModelViewer>,
+    mut selection: ResMut<Selection>,
+    mut measurements: ResMut<Measurements>,
+    panel_query: Query<&Interaction, With<DraggablePanel>>,
+    scroll_query: Query<&RelativeCursorPosition, With<ScrollArea>>,
+    parents: Query<&ChildOf>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut ray_cast: MeshRayCast,
+) {
+    for press in presses.read() {
+        if press.event.button == PointerButton::Primary {
+            *press_position = Some(press.pointer_location.position);
+        }
+    }
+
+    // Each click is reported for the entity under the pointer, so one click is enough
+    let Some(click) = clicks
+        .read()
+        .filter(|click| click.event.button == PointerButton::Primary)
+        .last()
+    else {
+        return;
+    };
+    let position = click.pointer_location.position;
+    let dragged = press_position
+        .take()
+        .is_some_and(|pressed| pressed.distance(position) > CLICK_TOLERANCE);
+    if dragged || cursor_over_ui(&panel_query, &scroll_query) {
+        return;
+    }
+
+    let Some(model) = viewer.current_model else {
+        return;
+    };
+    let on_model = parents
+        .iter_ancestors(click.entity)
+        .any(|ancestor| ancestor == model);
+    if !on_model {
+        // Missing the model while measuring keeps the selection
+        if !measurements.active {
+            selection.entity = None;
+            selection.pick = None;
+        }
+        return;
+    }
+
+    // The picking hit has no triangle, so cast again against the hit mesh alone
+    let entity = click.entity;
+    let hit = &click.event.hit;
+    let camera = cameras.get(hit.camera).ok();
+    let mesh_hit = camera
+        .and_then(|(camera, transform)| camera.viewport_to_world(transform, position).ok())
+        .and_then(|ray| {
+            let filter = |candidate: Entity| candidate == entity;
+            let settings = MeshRayCastSettings::default().with_filter(&filter);
+            ray_cast
+                .cast_ray(ray, &settings)
+                .first()
+                .map(|(_, mesh_hit)| mesh_hit.clone())
+        });
+    let point = mesh_hit
+        .as_ref()
+        .map(|mesh_hit| mesh_hit.point)
+        .or(hit.position)
+        .unwrap_or_default();
+
+    if measurements.active {
+        let snapped = mesh_hit
+            .as_ref()
+            .filter(|_| measurements.snap)
+            .and_then(|mesh_hit| mesh_hit.triangle)
+            .zip(camera)
+            .and_then(|(triangle, (camera, transform))| {
+                snap_to_vertex(triangle, position, camera, transform)
+            });
+        measurements.add_point(snapped.unwrap_or(point));
+        return;
+    }
+
+    selection.entity = Some(entity);
+    selection.pick = Some(PickHit {
+        entity,
+        point,
+        triangle: mesh_hit.and_then(|mesh_hit| mesh_hit.triangle_index),
+    });
+}
+
+/// Outline the selected node's meshes and mark the picked point
+pub fn draw_selection_highlight(
+    selection: Res<Selection>,
+    children: Query<&Children>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform)>,
+    mut gizmos: Gizmos<OverlayGizmos>,
+) {
+    let Some(selected) = selection.entity else {
+        return;
+    };
+
+    // A node without a mesh of its own is outlined through the meshes below it
+    for entity in std::iter::once(selected).chain(children.iter_descendants(selected)) {
+        if let Ok((aabb, transform)) = mesh_bounds.get(entity) {
+            gizmos.cuboid(aabb_box(aabb, transform), SELECTION_COLOR);
+        }
+    }
+
+    if let Some(pick) = selection.current_pick() {
+        let radius = mesh_bounds
+            .get(pick.entity)
+            .map_or(0.01, |(aabb, transform)| {
+                (transform.affine().matrix3 * aabb.half_extents).length() * 0.02
+            });
+        gizmos.sphere(
+            Isometry3d::from_translation(pick.point),
+            radius,
+            SELECTION_COLOR,
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use bevy::camera::primitives::Aabb;
+use bevy::math::bounding::BoundingVolume;
+use bevy::mesh::{PrimitiveTopology, VertexAttributeValues};
+use bevy::pbr::wireframe::WireframeConfig;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::bounds::model_bounds;
+use crate::components::OriginalMesh;
+use crate::resources::{DisplaySettings, ModelViewer, ShadingMode};
+
+/// Upper limit on normal and tangent lines per frame, so dense meshes stay interactive
+const MAX_VERTEX_LINES: usize = 100_000;
+
+const NORMAL_COLOR: Color = Color::srgb(0.3, 0.6, 1.0);
+const TANGENT_COLOR: Color = Color::srgb(1.0, 0.35, 0.35);
+
+pub fn apply_wireframe(settings: Res<DisplaySettings>, mut config: ResMut<WireframeConfig>) {
+    if settings.is_changed() && config.global != settings.wireframe {
+        config.global = settings.wireframe;
+    }
+}
+
+/// Swap the model's meshes for flat or smooth shaded copies, and back
+///
+/// Converts every mesh when the shading mode changes, and only new meshes while it stays the same,
+/// e.g. after switching scenes.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_shading_mode(
+    mut commands: Commands,
+    settings: Res<DisplaySettings>,
+    viewer: Res<ModelViewer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    children: Query<&Children>,
+    added: Query<(), Added<Mesh3d>>,
+    mut mesh_query: Query<(&mut Mesh3d, Option<&OriginalMesh>)>,
+    mut applied: Local<ShadingMode>,
+) {
+    let mode_changed = settings.shading != *applied;
+    if !mode_changed && (added.is_empty() || settings.shading == ShadingMode::Authored) {
+        return;
+    }
+    *applied = settings.shading;
+    let Some(model) = viewer.current_model else {
+        return;
+    };
+
+    // Meshes shared between nodes are converted once
+    let mut converted: HashMap<AssetId<Mesh>, Option<Handle<Mesh>>> = HashMap::new();
+
+    for entity in children.iter_descendants(model) {
+        if !mode_changed && !added.contains(entity) {
+            continue;
+        }
+        let Ok((mut mesh3d, original)) = mesh_query.get_mut(entity) else {
+            continue;
+        };
+        let original = original.map_or_else(|| mesh3d.0.clone(), |original| original.0.clone());
+
+        let replacement = match settings.shading {
+            ShadingMode::Authored => None,
+            mode => converted
+                .entry(original.id())
+                .or_insert_with(|| {
+                    let mesh = meshes.get(&original)?;
+                    reshade(mesh, mode).map(|mesh| meshes.add(mesh))
+                })
+                .clone(),
+        };
+
+        match replacement {
+            Some(handle) => {
+                mesh3d.0 = handle;
+                commands.entity(entity).insert(OriginalMesh(original));
+            }
+            None => {
+                if mesh3d.0 != original {
+                    mesh3d.0 = original;
+                }
+                commands.entity(entity).remove::<OriginalMesh>();
+            }
+        }
+    }
+}
+
+/// Copy of `mesh` with recomputed normals, or `None` if it can't be reshaded
+fn reshade(mesh: &Mesh, mode: ShadingMode) -> Option<Mesh> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let mut mesh = mesh.clone();
+    match mode {
+        ShadingMode::Authored => return None,
+        // Morph targets are indexed by vertex, so splitting vertices would break them
+        ShadingMode::Flat if mesh.has_morph_targets() => return None,
+        ShadingMode::Flat => {
+            mesh.duplicate_vertices();
+            mesh.compute_flat_normals();
+        }
+        // Without indices no vertices are shared, so there is nothing to smooth
+        ShadingMode::Smooth if mesh.indices().is_none() => return None,
+        ShadingMode::Smooth => mesh.compute_smooth_normals(),
+    }
+    Some(mesh)
+}
+
+/// Draw vertex normals and tangents of the model as short lines
+///
+/// Skinned and morphed meshes are drawn in their bind pose, since deformation happens on the GPU.
+pub fn draw_vertex_vectors(
+    settings: Res<DisplaySettings>,
+    viewer: Res<ModelViewer>,
+    meshes: Res<Assets<Mesh>>,
+    children: Query<&Children>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform)>,
+    mesh_query: Query<(&Mesh3d, &GlobalTransform, &InheritedVisibility)>,
+    mut gizmos: Gizmos,
+) {
+    if !settings.normals && !settings.tangents {
+        return;
+    }
+    let Some(model) = viewer.current_model else {
+        return;
+    };
+    let Some(bounds) = model_bounds(model, &children, &mesh_bounds) else {
+        return;
+    };
+    let length = bounds.half_size().length() * 0.02;
+
+    let visible: Vec<(&Mesh, &GlobalTransform)> = children
+        .iter_descendants(model)
+        .filter_map(|entity| mesh_query.get(entity).ok())
+        .filter(|(_, _, visibility)| visibility.get())
+        .filter_map(|(mesh3d, transform, _)| Some((meshes.get(&mesh3d.0)?, transform)))
+        .collect();
+
+    // Thin out evenly when there are more vertices than lines to spare
+    let vertices: usize = visible.iter().map(|(mesh, _)| mesh.count_vertices()).sum();
+    let step = vertices.div_ceil(MAX_VERTEX_LINES).max(1);
+
+    for (mesh, transform) in visible {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            continue;
+        };
+        let matrix = Mat3::from(transform.affine().matrix3);
+        // Normals need the inverse transpose to stay perpendicular under non-uniform scale
+        let normal_matrix = matrix.inverse().transpose();
+
+        let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(normals)) if settings.normals => Some(normals),
+            _ => None,
+        };
+        let tangents = match mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+            Some(VertexAttributeValues::Float32x4(tangents)) if settings.tangents => Some(tangents),
+            _ => None,
+        };
+
+        for i in (0..positions.len()).step_by(step) {
+            let start = transform.transform_point(Vec3::from(positions[i]));
+            if let Some(normal) = normals.and_then(|normals| normals.get(i)) {
+                let direction = (normal_matrix * Vec3::from(*normal)).normalize_or_zero();
+                gizmos.line(start, start + direction * length, NORMAL_COLOR);
+            }
+            if let Some(tangent) = tangents.and_then(|tangents| tangents.get(i)) {
+                let direction = (matrix * Vec4::from(*tangent).truncate()).normalize_or_zero();
+                gizmos.line(start, start + direction * length, TANGENT_COLOR);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Ground plane that is see-through except for the shadows falling on it
+
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    mesh_types::MESH_FLAGS_SHADOW_RECEIVER_BIT,
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::apply_pbr_lighting,
+}
+
+struct ShadowCatcher {
+    strength: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100)
+var<uniform> shadow_catcher: ShadowCatcher;
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+    let shadowed = apply_pbr_lighting(pbr_input);
+    pbr_input.flags &= ~MESH_FLAGS_SHADOW_RECEIVER_BIT;
+    let unshadowed = apply_pbr_lighting(pbr_input);
+
+    // Share of the light reaching the plane that the model blocks
+    let luminance = vec3<f32>(0.2126, 0.7152, 0.0722);
+    let blocked = 1.0 - dot(shadowed.rgb, luminance) / max(dot(unshadowed.rgb, luminance), 1e-5);
+
+    var out: FragmentOutput;
+    out.color = vec4<f32>(0.0, 0.0, 0.0, saturate(blocked) * shadow_catcher.strength);
+    return out;
+}
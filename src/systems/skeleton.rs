@@ -0,0 +1,167 @@
+use bevy::mesh::skinning::SkinnedMesh;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+
+use crate::components::JointLabel;
+use crate::resources::{DisplaySettings, Selection};
+
+/// Joints closer to the cursor than this are hovered, in logical pixels
+const JOINT_HOVER_RADIUS: f32 = 10.0;
+
+const BONE_COLOR: Color = Color::srgb(0.45, 0.7, 1.0);
+const CHAIN_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+const HOVER_COLOR: Color = Color::WHITE;
+
+/// Gizmos for the skeleton overlay, drawn on top of the meshes they deform
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct SkeletonGizmos;
+
+pub fn setup_skeleton_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+    let (config, _) = config_store.config_mut::<SkeletonGizmos>();
+    config.depth_bias = -1.0;
+    config.line.width = 1.5;
+}
+
+/// Draw the joints of every skinned mesh as octahedral bones, and name the joint under the cursor
+///
+/// Runs after transform propagation so the bones follow the animation without lagging a frame.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_skeleton(
+    settings: Res<DisplaySettings>,
+    selection: Res<Selection>,
+    skinned_meshes: Query<&SkinnedMesh>,
+    joint_query: Query<(&GlobalTransform, Option<&Name>)>,
+    parents: Query<&ChildOf>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    windows: Query<&Window>,
+    mut label_query: Query<(&mut Text, &mut Node), With<JointLabel>>,
+    mut gizmos: Gizmos<SkeletonGizmos>,
+) {
+    let mut hovered = None;
+
+    if settings.skeleton {
+        // Skins often share joints, so draw each one once
+        let mut seen = HashSet::new();
+        let joints: Vec<Entity> = skinned_meshes
+            .iter()
+            .flat_map(|skinned| skinned.joints.iter().copied())
+            .filter(|joint| seen.insert(*joint))
+            .collect();
+
+        // Ancestors and descendants of the selected node
+        let selected_ancestors: HashSet<Entity> = selection
+            .entity
+            .map(|selected| parents.iter_ancestors(selected).collect())
+            .unwrap_or_default();
+        let in_chain = |joint: Entity| {
+            selection.entity.is_some_and(|selected| {
+                joint == selected
+                    || selected_ancestors.contains(&joint)
+                    || parents
+                        .iter_ancestors(joint)
+                        .any(|ancestor| ancestor == selected)
+            })
+        };
+
+        // Bones run from each joint to its parent joint
+        let mut bones = Vec::new();
+        for &joint in &joints {
+            if settings.skeleton_chain_only && selection.entity.is_some() && !in_chain(joint) {
+                continue;
+            }
+            let Ok((transform, _)) = joint_query.get(joint) else {
+                continue;
+            };
+            let parent_position = parents
+                .get(joint)
+                .ok()
+                .map(ChildOf::parent)
+                .filter(|parent| seen.contains(parent))
+                .and_then(|parent| joint_query.get(parent).ok())
+                .map(|(parent_transform, _)| parent_transform.translation());
+            bones.push((joint, parent_position, transform.translation()));
+        }
+
+        let lengths: Vec<f32> = bones
+            .iter()
+            .filter_map(|(_, head, tail)| head.map(|head| head.distance(*tail)))
+            .collect();
+        let joint_radius = if lengths.is_empty() {
+            0.01
+        } else {
+            lengths.iter().sum::<f32>() / lengths.len() as f32 * 0.08
+        };
+
+        hovered = hovered_joint(&bones, &cameras, &windows);
+
+        for &(joint, head, tail) in &bones {
+            let color = if hovered.is_some_and(|(hovered, _)| hovered == joint) {
+                HOVER_COLOR
+            } else if in_chain(joint) {
+                CHAIN_COLOR
+            } else {
+                BONE_COLOR
+            };
+            if let Some(head) = head {
+                draw_bone(&mut gizmos, head, tail, color);
+            }
+            gizmos.sphere(Isometry3d::from_translation(tail), joint_radius, color);
+        }
+    }
+
+    for (mut text, mut node) in &mut label_query {
+        let Some((joint, cursor)) = hovered else {
+            node.display = Display::None;
+            continue;
+        };
+        let name = joint_query
+            .get(joint)
+            .ok()
+            .and_then(|(_, name)| name)
+            .map_or_else(|| format!("Joint {joint}"), |name| name.to_string());
+        if **text != name {
+            **text = name;
+        }
+        node.display = Display::Flex;
+        node.left = Val::Px(cursor.x + 12.0);
+        node.top = Val::Px(cursor.y - 6.0);
+    }
+}
+
+/// Joint drawn closest to the cursor on the active camera, with the cursor position
+fn hovered_joint(
+    bones: &[(Entity, Option<Vec3>, Vec3)],
+    cameras: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    windows: &Query<&Window>,
+) -> Option<(Entity, Vec2)> {
+    let cursor = windows.single().ok()?.cursor_position()?;
+    let (camera, camera_transform) = cameras.iter().find(|(camera, _)| camera.is_active)?;
+
+    bones
+        .iter()
+        .filter_map(|&(joint, _, position)| {
+            let screen = camera.world_to_viewport(camera_transform, position).ok()?;
+            Some((joint, screen.distance(cursor)))
+        })
+        .filter(|(_, distance)| *distance < JOINT_HOVER_RADIUS)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(joint, _)| (joint, cursor))
+}
+
+/// Octahedral bone pointing from `head` to `tail`, widest a fifth of the way along
+fn draw_bone(gizmos: &mut Gizmos<SkeletonGizmos>, head: Vec3, tail: Vec3, color: Color) {
+    let Some(direction) = (tail - head).try_normalize() else {
+        return;
+    };
+    let length = head.distance(tail);
+    let (a, b) = direction.any_orthonormal_pair();
+    let middle = head + direction * length * 0.2;
+    let width = length * 0.1;
+    let ring = [a, b, -a, -b].map(|offset| middle + offset * width);
+
+    for (i, &corner) in ring.iter().enumerate() {
+        gizmos.line(head, corner, color);
+        gizmos.line(corner, tail, color);
+        gizmos.line(corner, ring[(i + 1) % ring.len()], color);
+    }
+}
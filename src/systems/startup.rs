@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::cli::CliArgs;
+use crate::resources::ModelViewer;
+use crate::systems::load_model;
+
+/// Apply command-line options once the scene and camera exist
+pub fn apply_cli_args(
+    mut commands: Commands,
+    mut cli: ResMut<CliArgs>,
+    asset_server: Res<AssetServer>,
+    mut viewer: ResMut<ModelViewer>,
+    mut clear_color: ResMut<ClearColor>,
+    mut camera_query: Query<&mut PanOrbitCamera>,
+) {
+    if let Some(color) = cli.background {
+        clear_color.0 = color;
+    }
+
+    if let Some(preset) = cli.camera {
+        for mut camera in &mut camera_query {
+            preset.snap(&mut camera);
+        }
+    }
+
+    if let Some(path) = cli.model.take() {
+        let options = std::mem::take(&mut cli.load_options);
+        load_model(&mut commands, &asset_server, &mut viewer, path, options);
+    }
+}
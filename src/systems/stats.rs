@@ -0,0 +1,148 @@
+use bevy::gltf::{GltfMesh, GltfSkin};
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+
+use crate::resources::{ModelStats, ModelViewer, TextureStats};
+
+#[allow(clippy::too_many_arguments)]
+pub fn compute_model_stats(
+    viewer: Res<ModelViewer>,
+    asset_server: Res<AssetServer>,
+    mut stats: ResMut<ModelStats>,
+    gltf_assets: Res<Assets<Gltf>>,
+    gltf_meshes: Res<Assets<GltfMesh>>,
+    gltf_skins: Res<Assets<GltfSkin>>,
+    meshes: Res<Assets<Mesh>>,
+    materials: Res<Assets<StandardMaterial>>,
+    images: Res<Assets<Image>>,
+) {
+    let Some(gltf_handle) = &viewer.gltf_handle else {
+        return;
+    };
+    if stats.source == Some(gltf_handle.id()) {
+        return;
+    }
+    // Textures of .gltf files load after the file itself; ones that fail to load are left out
+    if asset_server
+        .recursive_dependency_load_state(gltf_handle)
+        .is_loading()
+    {
+        return;
+    }
+    let Some(gltf) = gltf_assets.get(gltf_handle) else {
+        return;
+    };
+
+    let mut new_stats = ModelStats {
+        source: Some(gltf_handle.id()),
+        meshes: gltf.meshes.len(),
+        materials: gltf.materials.len(),
+        skins: gltf.skins.len(),
+        animations: gltf.animations.len(),
+        ..default()
+    };
+
+    let primitives = gltf
+        .meshes
+        .iter()
+        .filter_map(|handle| gltf_meshes.get(handle))
+        .flat_map(|gltf_mesh| &gltf_mesh.primitives);
+    for primitive in primitives {
+        new_stats.primitives += 1;
+        if let Some(mesh) = meshes.get(&primitive.mesh) {
+            new_stats.vertices += mesh.count_vertices();
+            new_stats.triangles += triangle_count(mesh);
+        }
+    }
+
+    new_stats.joints = gltf
+        .skins
+        .iter()
+        .filter_map(|handle| gltf_skins.get(handle))
+        .map(|skin| skin.joints.len())
+        .sum();
+
+    // Textures are only reachable through the materials that use them
+    let mut seen = HashSet::new();
+    for material in gltf
+        .materials
+        .iter()
+        .filter_map(|handle| materials.get(handle))
+    {
+        let textures = [
+            &material.base_color_texture,
+            &material.metallic_roughness_texture,
+            &material.normal_map_texture,
+            &material.occlusion_texture,
+            &material.emissive_texture,
+        ];
+        for texture in textures.into_iter().flatten() {
+            if !seen.insert(texture.id()) {
+                continue;
+            }
+            let Some(image) = images.get(texture) else {
+                continue;
+            };
+            new_stats.textures.push(TextureStats {
+                label: texture
+                    .path()
+                    .and_then(|path| path.label())
+                    .unwrap_or("Texture")
+                    .to_string(),
+                width: image.width(),
+                height: image.height(),
+                format: format!("{:?}", image.texture_descriptor.format),
+                gpu_bytes: estimate_gpu_bytes(image),
+            });
+        }
+    }
+
+    // Keyframe counts live in the sampler accessors of the glTF document
+    new_stats.keyframes = gltf.source.as_ref().map(|document| {
+        document
+            .animations()
+            .flat_map(|animation| animation.samplers())
+            .map(|sampler| sampler.input().count())
+            .sum()
+    });
+
+    info!(
+        "Model stats: {} meshes, {} primitives, {} vertices, {} triangles, {} materials, {} textures",
+        new_stats.meshes,
+        new_stats.primitives,
+        new_stats.vertices,
+        new_stats.triangles,
+        new_stats.materials,
+        new_stats.textures.len()
+    );
+
+    *stats = new_stats;
+}
+
+fn triangle_count(mesh: &Mesh) -> usize {
+    let elements = mesh.indices().map_or(mesh.count_vertices(), Indices::len);
+    match mesh.primitive_topology() {
+        PrimitiveTopology::TriangleList => elements / 3,
+        PrimitiveTopology::TriangleStrip => elements.saturating_sub(2),
+        _ => 0,
+    }
+}
+
+/// GPU memory of an image including all mip levels and array layers
+fn estimate_gpu_bytes(image: &Image) -> u64 {
+    let descriptor = &image.texture_descriptor;
+    let (block_width, block_height) = descriptor.format.block_dimensions();
+    let block_bytes = descriptor.format.block_copy_size(None).unwrap_or(4) as u64;
+    let layers = descriptor.size.depth_or_array_layers as u64;
+
+    (0..descriptor.mip_level_count)
+        .map(|level| {
+            let width = (descriptor.size.width >> level).max(1);
+            let height = (descriptor.size.height >> level).max(1);
+            let blocks_x = width.div_ceil(block_width) as u64;
+            let blocks_y = height.div_ceil(block_height) as u64;
+            blocks_x * blocks_y * block_bytes * layers
+        })
+        .sum()
+}
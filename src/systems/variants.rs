@@ -0,0 +1,185 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::render::render_resource::Face;
+
+use crate::components::{OriginalMaterial, OriginalMesh};
+use crate::resources::{MaterialVariants, ModelViewer, PrimitiveVariants};
+
+/// Read the `KHR_materials_variants` names and mappings from the loaded glTF document
+pub fn collect_variants(
+    viewer: Res<ModelViewer>,
+    mut variants: ResMut<MaterialVariants>,
+    gltf_assets: Res<Assets<Gltf>>,
+) {
+    let Some(gltf_handle) = &viewer.gltf_handle else {
+        return;
+    };
+    if variants.source == Some(gltf_handle.id()) {
+        return;
+    }
+    let Some(gltf) = gltf_assets.get(gltf_handle) else {
+        return;
+    };
+
+    let mut new_variants = MaterialVariants {
+        source: Some(gltf_handle.id()),
+        ..default()
+    };
+
+    if let Some(document) = &gltf.source {
+        if let Some(names) = document.variants() {
+            new_variants.names = names.map(|variant| variant.name().to_string()).collect();
+        }
+        for mesh in document.meshes() {
+            for primitive in mesh.primitives() {
+                let mut entry = PrimitiveVariants {
+                    default_material: primitive.material().index(),
+                    ..default()
+                };
+                for mapping in primitive.mappings() {
+                    let Some(material) = mapping.material().index() else {
+                        continue;
+                    };
+                    for &variant in mapping.variants() {
+                        entry.materials.insert(variant as usize, material);
+                    }
+                }
+                if !entry.materials.is_empty() {
+                    new_variants
+                        .primitives
+                        .insert((mesh.index(), primitive.index()), entry);
+                }
+            }
+        }
+    }
+
+    *variants = new_variants;
+}
+
+/// Put the materials of the selected variant on the primitives it maps
+///
+/// Runs when the selection changes and when new meshes spawn, e.g. after switching scenes.
+/// Meshes under a mirrored transform get the `(inverted)` copy of the material, which culls
+/// front faces instead of back faces.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn apply_material_variant(
+    variants: Res<MaterialVariants>,
+    viewer: Res<ModelViewer>,
+    asset_server: Res<AssetServer>,
+    gltf_assets: Res<Assets<Gltf>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut inverted_copies: Local<HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>>,
+    children: Query<&Children>,
+    added: Query<(), Added<MeshMaterial3d<StandardMaterial>>>,
+    mut mesh_query: Query<(
+        &Mesh3d,
+        Option<&OriginalMesh>,
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&mut OriginalMaterial>,
+    )>,
+) {
+    if !variants.is_changed() && added.is_empty() {
+        return;
+    }
+    if variants.primitives.is_empty() {
+        return;
+    }
+    let (Some(model), Some(gltf_handle)) = (viewer.current_model, &viewer.gltf_handle) else {
+        return;
+    };
+    let (Some(gltf), Some(path)) = (gltf_assets.get(gltf_handle), gltf_handle.path()) else {
+        return;
+    };
+    // Primitives without a material of their own use the one the loader adds for them
+    let default_material =
+        asset_server.get_handle(GltfAssetLabel::DefaultMaterial.from_asset(path.clone()));
+
+    for entity in children.iter_descendants(model) {
+        let Ok((mesh3d, original_mesh, mut material, original_material)) =
+            mesh_query.get_mut(entity)
+        else {
+            continue;
+        };
+        // Shading modes swap meshes, so look up the authored one
+        let mesh = original_mesh.map_or(&mesh3d.0, |original| &original.0);
+        let Some((mesh_index, primitive_index)) = primitive_indices(mesh) else {
+            continue;
+        };
+        let Some(primitive) = variants.primitives.get(&(mesh_index, primitive_index)) else {
+            continue;
+        };
+        let current = original_material
+            .as_ref()
+            .map_or(&material.0, |original| &original.0);
+        let is_scale_inverted =
+            is_inverted_material(current) || inverted_copies.values().any(|copy| copy == current);
+        let handle = match primitive.material_index(variants.selected) {
+            Some(index) if is_scale_inverted => {
+                let label = GltfAssetLabel::Material {
+                    index,
+                    is_scale_inverted,
+                };
+                // The loader only makes inverted copies of materials mirrored nodes use by
+                // default, so variant-only materials get one made here
+                asset_server
+                    .get_handle(label.from_asset(path.clone()))
+                    .or_else(|| {
+                        let plain = gltf.materials.get(index)?;
+                        inverted_copy(plain, &mut materials, &mut inverted_copies)
+                    })
+            }
+            Some(index) => gltf.materials.get(index).cloned(),
+            None => default_material.clone(),
+        };
+        let Some(handle) = handle else {
+            continue;
+        };
+
+        // Debug views keep the authored material aside and restore it later
+        match original_material {
+            Some(mut original) => {
+                if original.0 != handle {
+                    original.0 = handle;
+                }
+            }
+            None => {
+                if material.0 != handle {
+                    material.0 = handle;
+                }
+            }
+        }
+    }
+}
+
+/// Whether `material` is the loader's copy for meshes under a mirrored transform
+fn is_inverted_material(material: &Handle<StandardMaterial>) -> bool {
+    material
+        .path()
+        .and_then(|path| path.label())
+        .is_some_and(|label| label.ends_with(" (inverted)"))
+}
+
+/// Copy of `plain` with face culling flipped, made once per material
+fn inverted_copy(
+    plain: &Handle<StandardMaterial>,
+    materials: &mut Assets<StandardMaterial>,
+    inverted_copies: &mut HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>,
+) -> Option<Handle<StandardMaterial>> {
+    if let Some(copy) = inverted_copies.get(&plain.id()) {
+        return Some(copy.clone());
+    }
+    let mut material = materials.get(plain)?.clone();
+    if material.cull_mode == Some(Face::Back) {
+        material.cull_mode = Some(Face::Front);
+    }
+    let copy = materials.add(material);
+    inverted_copies.insert(plain.id(), copy.clone());
+    Some(copy)
+}
+
+/// glTF mesh and primitive index of a mesh loaded from the `Mesh{m}/Primitive{p}` sub-asset
+fn primitive_indices(mesh: &Handle<Mesh>) -> Option<(usize, usize)> {
+    let label = mesh.path()?.label()?;
+    let (mesh_index, primitive_index) = label.strip_prefix("Mesh")?.split_once("/Primitive")?;
+    Some((mesh_index.parse().ok()?, primitive_index.parse().ok()?))
+}
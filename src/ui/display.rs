@@ -0,0 +1,268 @@
+use bevy::prelude::*;
+
+use super::interactions::small_button_color;
+use super::layout::{spawn_panel, spawn_small_button};
+use crate::components::*;
+use crate::resources::{DisplayOption, DisplaySettings, Measurements};
+
+pub fn setup_display_panel(
+    mut commands: Commands,
+    settings: Res<DisplaySettings>,
+    measurements: Res<Measurements>,
+) {
+    let content = spawn_panel(&mut commands, "Display", Vec2::new(220.0, 450.0), 240.0);
+    commands.entity(content).with_children(|content| {
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(4.0),
+                row_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                for option in DisplayOption::ALL {
+                    let label = toggle_label(option, &settings);
+                    spawn_small_button(row, DisplayToggle(option), &label, 1.0);
+                }
+            });
+
+        spawn_small_button(content, ShadingButton, &shading_label(&settings), 0.0);
+        spawn_small_button(
+            content,
+            MaterialViewButton,
+            &material_view_label(&settings),
+            0.0,
+        );
+
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, MeasureButton, &measure_label(&measurements), 1.0);
+                spawn_small_button(row, MeasureSnapButton, &snap_label(&measurements), 1.0);
+                spawn_small_button(row, ClearMeasurementsButton, "Clear", 0.0);
+            });
+    });
+
+    // Follows the cursor while a skeleton joint is hovered
+    commands.spawn((
+        JointLabel,
+        Text::new(""),
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            display: Display::None,
+            padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.85)),
+        BorderRadius::all(Val::Px(3.0)),
+        GlobalZIndex(10),
+    ));
+
+    // Placed next to the bounding box edges they measure, see `draw_bounds`
+    for label in [
+        DimensionLabel::Width,
+        DimensionLabel::Height,
+        DimensionLabel::Depth,
+        DimensionLabel::Selection,
+    ] {
+        commands.spawn((
+            label,
+            Text::new(""),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.75)),
+            BorderRadius::all(Val::Px(3.0)),
+            GlobalZIndex(9),
+            // Clicks go through to the model underneath
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn display_interactions(
+    mut settings: ResMut<DisplaySettings>,
+    mut buttons: Query<(&Interaction, &DisplayToggle, &mut BackgroundColor), Changed<Interaction>>,
+    mut shading_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<ShadingButton>,
+            Without<DisplayToggle>,
+        ),
+    >,
+    mut material_view_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<MaterialViewButton>,
+            Without<DisplayToggle>,
+            Without<ShadingButton>,
+        ),
+    >,
+) {
+    for (interaction, toggle, mut bg) in &mut buttons {
+        if *interaction == Interaction::Pressed {
+            settings.toggle(toggle.0);
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut shading_btn {
+        if *interaction == Interaction::Pressed {
+            settings.shading = settings.shading.next();
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut material_view_btn {
+        if *interaction == Interaction::Pressed {
+            settings.material_view = settings.material_view.next();
+        }
+        *bg = small_button_color(*interaction);
+    }
+}
+
+pub fn update_display_panel(
+    settings: Res<DisplaySettings>,
+    buttons: Query<(&DisplayToggle, &Children)>,
+    shading_btn: Query<&Children, With<ShadingButton>>,
+    material_view_btn: Query<&Children, With<MaterialViewButton>>,
+    mut texts: Query<&mut Text>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (toggle, children) in &buttons {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = toggle_label(toggle.0, &settings);
+            }
+        }
+    }
+    for children in &shading_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = shading_label(&settings);
+            }
+        }
+    }
+    for children in &material_view_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = material_view_label(&settings);
+            }
+        }
+    }
+}
+
+fn toggle_label(option: DisplayOption, settings: &DisplaySettings) -> String {
+    let state = if settings.is_enabled(option) {
+        "On"
+    } else {
+        "Off"
+    };
+    format!("{}: {state}", option.label())
+}
+
+fn shading_label(settings: &DisplaySettings) -> String {
+    format!("Shading: {}", settings.shading.label())
+}
+
+fn material_view_label(settings: &DisplaySettings) -> String {
+    format!("Channel: {}", settings.material_view.label())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn measure_interactions(
+    mut measurements: ResMut<Measurements>,
+    mut buttons: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Has<MeasureButton>,
+            Has<MeasureSnapButton>,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(
+                With<MeasureButton>,
+                With<MeasureSnapButton>,
+                With<ClearMeasurementsButton>,
+            )>,
+        ),
+    >,
+) {
+    for (interaction, mut bg, is_measure, is_snap) in &mut buttons {
+        *bg = small_button_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if is_measure {
+            measurements.active = !measurements.active;
+        } else if is_snap {
+            measurements.snap = !measurements.snap;
+        } else {
+            measurements.clear();
+        }
+    }
+}
+
+pub fn update_measure_buttons(
+    measurements: Res<Measurements>,
+    measure_btn: Query<&Children, With<MeasureButton>>,
+    snap_btn: Query<&Children, With<MeasureSnapButton>>,
+    mut texts: Query<&mut Text>,
+) {
+    if !measurements.is_changed() {
+        return;
+    }
+
+    for children in &measure_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = measure_label(&measurements);
+            }
+        }
+    }
+    for children in &snap_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = snap_label(&measurements);
+            }
+        }
+    }
+}
+
+fn measure_label(measurements: &Measurements) -> String {
+    match (measurements.active, measurements.pending) {
+        (false, _) => "Measure: Off".to_string(),
+        (true, None) => "Measure: On".to_string(),
+        (true, Some(_)) => "Measure: 2nd point".to_string(),
+    }
+}
+
+fn snap_label(measurements: &Measurements) -> String {
+    let state = if measurements.snap { "On" } else { "Off" };
+    format!("Snap: {state}")
+}
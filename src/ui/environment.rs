@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+use bevy_file_dialog::prelude::*;
+
+use super::interactions::small_button_color;
+use super::layout::{spawn_panel, spawn_slider, spawn_small_button};
+use crate::components::*;
+use crate::resources::{EnvironmentSettings, MAX_ENVIRONMENT_INTENSITY};
+use crate::systems::use_default_environment;
+
+pub fn setup_environment_panel(mut commands: Commands, settings: Res<EnvironmentSettings>) {
+    let content = spawn_panel(&mut commands, "Environment", Vec2::new(990.0, 10.0), 220.0);
+    commands.entity(content).with_children(|content| {
+        content.spawn((
+            EnvironmentLabel,
+            Text::new(""),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            TextLayout::new_with_linebreak(LineBreak::AnyCharacter),
+        ));
+
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, EnvironmentLoadButton, "Load HDR/EXR/KTX2...", 1.0);
+                spawn_small_button(row, EnvironmentDefaultButton, "Default", 0.0);
+            });
+
+        spawn_small_button(content, SkyboxButton, "Skybox: On", 0.0);
+
+        content.spawn((
+            EnvironmentIntensityLabel,
+            Text::new(""),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+        spawn_slider(
+            content,
+            EnvironmentIntensitySlider,
+            settings.intensity / MAX_ENVIRONMENT_INTENSITY,
+        );
+
+        content.spawn((
+            EnvironmentRotationLabel,
+            Text::new(""),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+        spawn_slider(
+            content,
+            EnvironmentRotationSlider,
+            settings.rotation / 360.0,
+        );
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn environment_interactions(
+    mut commands: Commands,
+    mut settings: ResMut<EnvironmentSettings>,
+    mut images: ResMut<Assets<Image>>,
+    mut buttons: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Has<EnvironmentLoadButton>,
+            Has<EnvironmentDefaultButton>,
+            Has<SkyboxButton>,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(
+                With<EnvironmentLoadButton>,
+                With<EnvironmentDefaultButton>,
+                With<SkyboxButton>,
+            )>,
+        ),
+    >,
+    intensity_slider: Query<&Slider, (Changed<Slider>, With<EnvironmentIntensitySlider>)>,
+    rotation_slider: Query<&Slider, (Changed<Slider>, With<EnvironmentRotationSlider>)>,
+) {
+    for (interaction, mut bg, is_load, is_default, is_skybox) in &mut buttons {
+        *bg = small_button_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if is_load {
+            commands
+                .dialog()
+                .add_filter("Environment map", &["hdr", "exr", "ktx2"])
+                .load_file::<EnvironmentFile>();
+        } else if is_default {
+            use_default_environment(&mut settings, &mut images);
+        } else if is_skybox {
+            settings.show_skybox = !settings.show_skybox;
+        }
+    }
+
+    for slider in &intensity_slider {
+        settings.intensity = slider.value * MAX_ENVIRONMENT_INTENSITY;
+    }
+    for slider in &rotation_slider {
+        settings.rotation = slider.value * 360.0;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_environment_panel(
+    settings: Res<EnvironmentSettings>,
+    mut name_label: Query<&mut Text, With<EnvironmentLabel>>,
+    mut intensity_label: Query<
+        &mut Text,
+        (With<EnvironmentIntensityLabel>, Without<EnvironmentLabel>),
+    >,
+    mut rotation_label: Query<
+        &mut Text,
+        (
+            With<EnvironmentRotationLabel>,
+            Without<EnvironmentLabel>,
+            Without<EnvironmentIntensityLabel>,
+        ),
+    >,
+    skybox_btn: Query<&Children, With<SkyboxButton>>,
+    mut button_text: Query<
+        &mut Text,
+        (
+            Without<EnvironmentLabel>,
+            Without<EnvironmentIntensityLabel>,
+            Without<EnvironmentRotationLabel>,
+        ),
+    >,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for mut text in &mut name_label {
+        **text = match &settings.pending {
+            Some((_, name)) => format!("Loading {name}..."),
+            None => settings.name.clone(),
+        };
+    }
+    for mut text in &mut intensity_label {
+        **text = format!("Intensity {:.0} cd/m2", settings.intensity);
+    }
+    for mut text in &mut rotation_label {
+        **text = format!("Rotation {:.0} deg", settings.rotation);
+    }
+    for children in &skybox_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = if settings.show_skybox {
+                    "Skybox: On".to_string()
+                } else {
+                    "Skybox: Off".to_string()
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use bevy::gltf::{GltfMaterialExtras, GltfMeshExtras};
+use bevy::mesh::skinning::SkinnedMesh;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use std::fmt::Write;
+
+use super::layout::spawn_panel;
+use crate::components::*;
+use crate::resources::Selection;
+
+/// Joint names listed before the skin section is truncated
+const MAX_LISTED_JOINTS: usize = 12;
+
+/// Components of the selected node shown in the inspector
+type InspectedNode<'a> = (
+    Option<&'a Name>,
+    &'a Transform,
+    &'a GlobalTransform,
+    Option<&'a Mesh3d>,
+    Option<&'a MeshMaterial3d<StandardMaterial>>,
+    Option<&'a SkinnedMesh>,
+    Option<&'a MorphWeights>,
+    Option<&'a GltfExtras>,
+    Option<&'a GltfMeshExtras>,
+    Option<&'a GltfMaterialExtras>,
+);
+
+pub fn setup_inspector(mut commands: Commands) {
+    let content = spawn_panel(&mut commands, "Inspector", Vec2::new(470.0, 10.0), 260.0);
+    commands.entity(content).with_children(|content| {
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(420.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+            ))
+            .with_child((
+                InspectorText,
+                Text::new("No node selected"),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                TextLayout::new_with_linebreak(LineBreak::AnyCharacter),
+            ));
+    });
+}
+
+/// Rebuild the inspector text every frame so it follows running animations
+pub fn update_inspector(
+    selection: Res<Selection>,
+    nodes: Query<InspectedNode>,
+    names: Query<&Name>,
+    parents: Query<&ChildOf>,
+    meshes: Res<Assets<Mesh>>,
+    materials: Res<Assets<StandardMaterial>>,
+    mut inspector_text: Query<&mut Text, With<InspectorText>>,
+) {
+    let Ok(mut text) = inspector_text.single_mut() else {
+        return;
+    };
+
+    let Some((
+        name,
+        transform,
+        global,
+        mesh,
+        material,
+        skin,
+        morph_weights,
+        extras,
+        mesh_extras,
+        material_extras,
+    )) = selection.entity.and_then(|entity| nodes.get(entity).ok())
+    else {
+        **text = "No node selected".to_string();
+        return;
+    };
+
+    // Writing to a String cannot fail, so the results are ignored
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{}",
+        name.map_or_else(|| "Unnamed node".to_string(), Name::to_string)
+    );
+
+    if let Some(pick) = selection.current_pick() {
+        let _ = writeln!(
+            out,
+            "\nPicked  {}",
+            node_path(pick.entity, &parents, &names)
+        );
+        if let Some(triangle) = pick.triangle {
+            let _ = writeln!(out, "  triangle {triangle}");
+        }
+        let p = pick.point;
+        let _ = writeln!(out, "  at {:>8.3} {:>8.3} {:>8.3}", p.x, p.y, p.z);
+    }
+
+    let _ = writeln!(out, "\nLocal");
+    write_transform(&mut out, transform);
+    let _ = writeln!(out, "\nGlobal");
+    write_transform(&mut out, &global.compute_transform());
+
+    if let Some(mesh) = mesh {
+        let _ = writeln!(out, "\nMesh  {}", asset_label(&mesh.0));
+        if let Some(mesh) = meshes.get(&mesh.0) {
+            let _ = writeln!(out, "  vertices {}", mesh.count_vertices());
+            let _ = writeln!(out, "  topology {:?}", mesh.primitive_topology());
+        }
+    }
+
+    if let Some(material) = material {
+        let _ = writeln!(out, "\nMaterial  {}", asset_label(&material.0));
+        if let Some(material) = materials.get(&material.0) {
+            let base = material.base_color.to_srgba();
+            let _ = writeln!(
+                out,
+                "  base  {:.2} {:.2} {:.2} {:.2}",
+                base.red, base.green, base.blue, base.alpha
+            );
+            let _ = writeln!(
+                out,
+                "  metallic {:.2}  roughness {:.2}",
+                material.metallic, material.perceptual_roughness
+            );
+        }
+    }
+
+    if let Some(skin) = skin {
+        let _ = writeln!(out, "\nSkin  {} joints", skin.joints.len());
+        for joint in skin.joints.iter().take(MAX_LISTED_JOINTS) {
+            let joint_name = names
+                .get(*joint)
+                .map_or_else(|_| format!("Entity {}", joint.index()), Name::to_string);
+            let _ = writeln!(out, "  {joint_name}");
+        }
+        if skin.joints.len() > MAX_LISTED_JOINTS {
+            let _ = writeln!(out, "  ... {} more", skin.joints.len() - MAX_LISTED_JOINTS);
+        }
+    }
+
+    if let Some(morph_weights) = morph_weights {
+        let _ = writeln!(out, "\nMorph weights");
+        for (i, weight) in morph_weights.weights().iter().enumerate() {
+            let _ = writeln!(out, "  [{i}] {weight:.3}");
+        }
+    }
+
+    for (label, value) in [
+        ("Extras", extras.map(|extras| &extras.value)),
+        ("Mesh extras", mesh_extras.map(|extras| &extras.value)),
+        (
+            "Material extras",
+            material_extras.map(|extras| &extras.value),
+        ),
+    ] {
+        if let Some(value) = value {
+            let _ = writeln!(out, "\n{label}\n  {value}");
+        }
+    }
+
+    if **text != out {
+        **text = out;
+    }
+}
+
+fn write_transform(out: &mut String, transform: &Transform) {
+    let t = transform.translation;
+    let (x, y, z) = transform.rotation.to_euler(EulerRot::XYZ);
+    let s = transform.scale;
+    let _ = writeln!(out, "  T {:>8.3} {:>8.3} {:>8.3}", t.x, t.y, t.z);
+    let _ = writeln!(
+        out,
+        "  R {:>8.2} {:>8.2} {:>8.2}",
+        x.to_degrees(),
+        y.to_degrees(),
+        z.to_degrees()
+    );
+    let _ = writeln!(out, "  S {:>8.3} {:>8.3} {:>8.3}", s.x, s.y, s.z);
+}
+
+/// Names of the node and its ancestors, root first, separated by `/`
+fn node_path(entity: Entity, parents: &Query<&ChildOf>, names: &Query<&Name>) -> String {
+    let mut path: Vec<String> = std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .filter_map(|node| names.get(node).ok())
+        .map(Name::to_string)
+        .collect();
+    path.reverse();
+    path.join("/")
+}
+
+/// Label of a glTF sub-asset, such as `Mesh0/Primitive0`
+fn asset_label<A: Asset>(handle: &Handle<A>) -> String {
+    handle
+        .path()
+        .and_then(|path| path.label())
+        .map_or_else(|| "(generated)".to_string(), str::to_string)
+}
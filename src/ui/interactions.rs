@@ -1,9 +1,13 @@
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 use bevy_file_dialog::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
 
 use crate::components::*;
-use crate::resources::ModelViewer;
+use crate::resources::{ActiveCamera, ModelViewer};
+use crate::systems::{reframe_model, switch_scene, toggle_projection};
 
+#[allow(clippy::type_complexity)]
 pub fn button_interactions(
     mut commands: Commands,
     mut viewer: ResMut<ModelViewer>,
@@ -19,6 +23,15 @@ pub fn button_interactions(
             Without<OpenButton>,
         ),
     >,
+    mut sort_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<SortOrderButton>,
+            Without<OpenButton>,
+            Without<PlayPauseButton>,
+        ),
+    >,
 ) {
     // Open button
     for (interaction, mut bg) in &mut open_btn {
@@ -61,6 +74,15 @@ pub fn button_interactions(
             }
         }
     }
+
+    // Sort order button
+    for (interaction, mut bg) in &mut sort_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.sort_order = viewer.sort_order.next();
+            viewer.sort_animations();
+        }
+        *bg = small_button_color(*interaction);
+    }
 }
 
 pub fn animation_list_interactions(
@@ -90,3 +112,278 @@ pub fn animation_list_interactions(
         }
     }
 }
+
+#[allow(clippy::type_complexity)]
+pub fn camera_interactions(
+    mut commands: Commands,
+    viewer: Res<ModelViewer>,
+    mut active: ResMut<ActiveCamera>,
+    mut camera_query: Query<(&mut PanOrbitCamera, &mut Projection)>,
+    mut frame_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<FrameModelButton>),
+    >,
+    mut projection_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<ProjectionButton>,
+            Without<FrameModelButton>,
+        ),
+    >,
+    mut preset_btn: Query<
+        (&Interaction, &ViewPresetButton, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            Without<FrameModelButton>,
+            Without<ProjectionButton>,
+        ),
+    >,
+) {
+    // Orbit camera controls switch back to the orbit camera
+    for (interaction, mut bg) in &mut frame_btn {
+        if *interaction == Interaction::Pressed {
+            reframe_model(&mut commands, &viewer);
+            active.scene_camera = None;
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut projection_btn {
+        if *interaction == Interaction::Pressed {
+            active.scene_camera = None;
+            for (mut camera, mut projection) in &mut camera_query {
+                toggle_projection(&mut camera, &mut projection);
+            }
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, button, mut bg) in &mut preset_btn {
+        if *interaction == Interaction::Pressed {
+            active.scene_camera = None;
+            for (mut camera, _) in &mut camera_query {
+                button.0.animate(&mut camera);
+            }
+        }
+        *bg = small_button_color(*interaction);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn camera_dropdown_interactions(
+    mut active: ResMut<ActiveCamera>,
+    mut dropdown_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<CameraDropdownButton>),
+    >,
+    mut dropdown_list: Query<&mut Node, With<CameraDropdownList>>,
+    mut items: Query<
+        (&Interaction, &CameraListItem, &mut BackgroundColor),
+        (Changed<Interaction>, Without<CameraDropdownButton>),
+    >,
+) {
+    for (interaction, mut bg) in &mut dropdown_btn {
+        if *interaction == Interaction::Pressed {
+            for mut node in &mut dropdown_list {
+                node.display = match node.display {
+                    Display::None => Display::Flex,
+                    _ => Display::None,
+                };
+            }
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, item, mut bg) in &mut items {
+        let is_selected = active.scene_camera == item.0;
+        match *interaction {
+            Interaction::Pressed => {
+                active.scene_camera = item.0;
+                for mut node in &mut dropdown_list {
+                    node.display = Display::None;
+                }
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                if is_selected {
+                    *bg = BackgroundColor(Color::srgb(0.2, 0.35, 0.5));
+                } else {
+                    *bg = BackgroundColor(Color::NONE);
+                }
+            }
+        }
+    }
+}
+
+pub fn scene_list_interactions(
+    mut commands: Commands,
+    mut viewer: ResMut<ModelViewer>,
+    mut items: Query<(&Interaction, &SceneListItem, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, item, mut bg) in &mut items {
+        let is_selected = viewer.current_scene == item.0;
+        match *interaction {
+            Interaction::Pressed => switch_scene(&mut commands, &mut viewer, item.0),
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                if is_selected {
+                    *bg = BackgroundColor(Color::srgb(0.2, 0.35, 0.5));
+                } else {
+                    *bg = BackgroundColor(Color::NONE);
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn timeline_interactions(
+    mut viewer: ResMut<ModelViewer>,
+    mut players: Query<&mut AnimationPlayer>,
+    timeline_bar: Query<(&Interaction, &RelativeCursorPosition), With<TimelineBar>>,
+    mut step_btn: Query<
+        (&Interaction, &FrameStepButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut fps_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<FrameRateButton>,
+            Without<FrameStepButton>,
+        ),
+    >,
+) {
+    // Frame rate button
+    for (interaction, mut bg) in &mut fps_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.frame_rate = viewer.frame_rate.next();
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    // Selected clip node and duration, if there is anything to seek
+    let clip = viewer
+        .current_entry()
+        .map(|entry| (entry.node, entry.duration))
+        .filter(|(_, duration)| *duration > 0.0);
+
+    // Scrub while the timeline track is held
+    for (interaction, rel_pos) in &timeline_bar {
+        let (Interaction::Pressed, Some(pos), Some((node, duration))) =
+            (*interaction, rel_pos.normalized, clip)
+        else {
+            continue;
+        };
+        let time = (pos.x + 0.5).clamp(0.0, 1.0) * duration;
+        for mut player in &mut players {
+            if let Some(active) = player.animation_mut(node) {
+                active.set_seek_time(time);
+            }
+        }
+    }
+
+    // Frame step buttons pause playback and snap to the neighbouring frame
+    let frame_rate = viewer.frame_rate;
+    for (interaction, step, mut bg) in &mut step_btn {
+        *bg = small_button_color(*interaction);
+        let (Interaction::Pressed, Some((node, duration))) = (*interaction, clip) else {
+            continue;
+        };
+        for mut player in &mut players {
+            if let Some(active) = player.animation_mut(node) {
+                let frame = frame_rate.frame_at(active.seek_time()) + step.0;
+                let time = frame as f32 / frame_rate.fps();
+                active.set_seek_time(time.clamp(0.0, duration));
+            }
+        }
+        viewer.is_playing = false;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn playback_interactions(
+    mut viewer: ResMut<ModelViewer>,
+    mut speed_btn: Query<(&Interaction, &SpeedButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut reverse_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<ReverseButton>,
+            Without<SpeedButton>,
+        ),
+    >,
+    mut loop_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<LoopModeButton>,
+            Without<SpeedButton>,
+            Without<ReverseButton>,
+        ),
+    >,
+    mut crossfade_btn: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<CrossfadeButton>,
+            Without<SpeedButton>,
+            Without<ReverseButton>,
+            Without<LoopModeButton>,
+        ),
+    >,
+) {
+    for (interaction, step, mut bg) in &mut speed_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.change_speed(step.0);
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut reverse_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.reverse = !viewer.reverse;
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut loop_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.loop_mode = viewer.loop_mode.next();
+        }
+        *bg = small_button_color(*interaction);
+    }
+
+    for (interaction, mut bg) in &mut crossfade_btn {
+        if *interaction == Interaction::Pressed {
+            viewer.next_crossfade();
+        }
+        *bg = small_button_color(*interaction);
+    }
+}
+
+/// Background color of the compact panel buttons for an interaction state
+pub fn small_button_color(interaction: Interaction) -> BackgroundColor {
+    match interaction {
+        Interaction::Pressed => BackgroundColor(Color::srgb(0.14, 0.14, 0.18)),
+        Interaction::Hovered => BackgroundColor(Color::srgb(0.28, 0.28, 0.36)),
+        Interaction::None => BackgroundColor(Color::srgb(0.18, 0.18, 0.22)),
+    }
+}
+
+pub fn drag_sliders(mut sliders: Query<(&Interaction, &RelativeCursorPosition, &mut Slider)>) {
+    for (interaction, rel_pos, mut slider) in &mut sliders {
+        let (Interaction::Pressed, Some(pos)) = (*interaction, rel_pos.normalized) else {
+            continue;
+        };
+        let value = (pos.x + 0.5).clamp(0.0, 1.0);
+        if slider.value != value {
+            slider.value = value;
+        }
+    }
+}
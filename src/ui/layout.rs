@@ -3,6 +3,7 @@ use bevy::ui::RelativeCursorPosition;
 use bevy_panorbit_camera::PanOrbitCamera;
 
 use crate::components::*;
+use crate::systems::CameraPreset;
 
 pub fn setup_scene(mut commands: Commands) {
     // Spawn 3D camera with orbit controls
@@ -15,198 +16,465 @@ pub fn setup_scene(mut commands: Commands) {
         },
     ));
 
+    // UI gets its own camera so it stays visible when viewing through a glTF camera
+    commands.spawn((
+        Camera2d,
+        Camera {
+            order: 1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        IsDefaultUiCamera,
+    ));
+
     // Add ambient light
     commands.spawn(AmbientLight {
         color: Color::WHITE,
         brightness: 500.0,
         ..default()
     });
-
-    // Add directional light
-    commands.spawn((
-        DirectionalLight {
-            color: Color::WHITE,
-            illuminance: 10000.0,
-            shadows_enabled: true,
-            ..default()
-        },
-        Transform::from_xyz(5.0, 10.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
-    ));
 }
 
 pub fn setup_ui(mut commands: Commands) {
-    // Root UI node (full screen container)
+    let content = spawn_panel(&mut commands, "Model Viewer", Vec2::new(10.0, 10.0), 200.0);
+    commands.entity(content).with_children(|content| {
+        // Open Model button - compact
+        content
+            .spawn((
+                Button,
+                OpenButton,
+                Node {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.25, 0.25, 0.55)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                Text::new("Open Model..."),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+        // Camera framing and projection
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, FrameModelButton, "Frame model", 1.0);
+                spawn_small_button(row, ProjectionButton, "Persp", 0.0);
+            });
+
+        // Camera dropdown: orbit camera plus the cameras in the glTF scene
+        spawn_small_button(content, CameraDropdownButton, "View: Orbit camera", 0.0);
+        content.spawn((
+            CameraDropdownList,
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+            BorderRadius::all(Val::Px(3.0)),
+        ));
+
+        // Camera view presets, front/right/top above their opposites
+        for presets in [
+            [CameraPreset::Front, CameraPreset::Right, CameraPreset::Top],
+            [CameraPreset::Back, CameraPreset::Left, CameraPreset::Bottom],
+        ] {
+            content
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for preset in presets {
+                        spawn_small_button(row, ViewPresetButton(preset), preset.label(), 1.0);
+                    }
+                });
+        }
+
+        // Model name label - smaller, with wrapping
+        content.spawn((
+            ModelLabel,
+            Node {
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            Text::new("No model loaded"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            TextLayout::new_with_linebreak(LineBreak::WordBoundary),
+        ));
+
+        // Scene list (scrollable), empty until a model is loaded
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(60.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                SceneListContainer,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+            ));
+
+        // Separator
+        content.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(1.0),
+                margin: UiRect::axes(Val::Px(0.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 0.5)),
+        ));
+
+        // Current animation label (no separate title)
+        content.spawn((
+            Text::new("Selected: None"),
+            AnimationLabel,
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+
+        // List order and crossfade toggles
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, SortOrderButton, "Sort: File order", 1.0);
+                spawn_small_button(row, CrossfadeButton, "Fade: Off", 0.0);
+            });
+
+        // Animation list (scrollable)
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(180.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_children(|list_container| {
+                list_container.spawn((
+                    AnimationListContainer,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+            });
+
+        // Timeline track - click or drag to scrub
+        content
+            .spawn((
+                TimelineBar,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(8.0),
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(2.0)),
+            ))
+            .with_child((
+                TimelineFill,
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.3, 0.5, 0.75)),
+                BorderRadius::all(Val::Px(2.0)),
+            ));
+
+        // Timeline readout
+        content.spawn((
+            TimelineLabel,
+            Text::new("0.00 / 0.00 s"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+
+        // Frame step and frame rate controls
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, FrameStepButton(-1), "<", 0.0);
+                spawn_small_button(row, FrameRateButton, "30 fps", 1.0);
+                spawn_small_button(row, FrameStepButton(1), ">", 0.0);
+            });
+
+        // Playback speed controls
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, SpeedButton(-1), "-", 0.0);
+                row.spawn((
+                    SpeedLabel,
+                    Node {
+                        flex_grow: 1.0,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    Text::new("1.00x"),
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    TextLayout::new_with_justify(Justify::Center),
+                ));
+                spawn_small_button(row, SpeedButton(1), "+", 0.0);
+            });
+
+        // Direction and loop mode toggles
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, ReverseButton, "Forward", 1.0);
+                spawn_small_button(row, LoopModeButton, "Loop", 1.0);
+            });
+
+        // Play/Pause button - compact
+        content
+            .spawn((
+                Button,
+                PlayPauseButton,
+                Node {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
+                    justify_content: JustifyContent::Center,
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.45, 0.2)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                Text::new("Play"),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+    });
+}
+
+/// Spawn a floating draggable panel with a title bar, returning its content node
+pub fn spawn_panel(commands: &mut Commands, title: &str, position: Vec2, width: f32) -> Entity {
+    let mut content = Entity::PLACEHOLDER;
+
     commands
-        .spawn(Node {
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            position_type: PositionType::Absolute,
-            ..default()
-        })
-        .with_children(|parent| {
-            // Floating draggable panel - compact design
-            parent
+        .spawn((
+            DraggablePanel,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                flex_direction: FlexDirection::Column,
+                width: Val::Px(width),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
+            BorderRadius::all(Val::Px(4.0)),
+        ))
+        .with_children(|panel| {
+            // Drag handle / Title bar - compact
+            panel
                 .spawn((
-                    DraggablePanel,
-                    Interaction::default(),
+                    PanelDragArea,
                     Node {
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(10.0),
-                        top: Val::Px(10.0),
-                        flex_direction: FlexDirection::Column,
-                        width: Val::Px(200.0),
+                        width: Val::Percent(100.0),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                        border: UiRect::bottom(Val::Px(1.0)),
                         ..default()
                     },
-                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
-                    BorderRadius::all(Val::Px(4.0)),
+                    BackgroundColor(Color::srgba(0.18, 0.18, 0.18, 1.0)),
+                    BorderColor::all(Color::srgba(0.25, 0.25, 0.25, 1.0)),
+                    BorderRadius::top(Val::Px(4.0)),
+                    RelativeCursorPosition::default(),
                 ))
-                .with_children(|panel| {
-                    // Drag handle / Title bar - compact
-                    panel
-                        .spawn((
-                            PanelDragArea,
-                            Node {
-                                width: Val::Percent(100.0),
-                                padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
-                                border: UiRect::bottom(Val::Px(1.0)),
-                                ..default()
-                            },
-                            BackgroundColor(Color::srgba(0.18, 0.18, 0.18, 1.0)),
-                            BorderColor::all(Color::srgba(0.25, 0.25, 0.25, 1.0)),
-                            BorderRadius::top(Val::Px(4.0)),
-                            RelativeCursorPosition::default(),
-                        ))
-                        .with_child((
-                            Text::new("Model Viewer"),
-                            TextFont {
-                                font_size: 12.0,
-                                ..default()
-                            },
-                            TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                        ));
-
-                    // Content area - compact padding
-                    panel
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Column,
-                            padding: UiRect::all(Val::Px(8.0)),
-                            row_gap: Val::Px(6.0),
-                            ..default()
-                        })
-                        .with_children(|content| {
-                            // Open Model button - compact
-                            content
-                                .spawn((
-                                    Button,
-                                    OpenButton,
-                                    Node {
-                                        width: Val::Percent(100.0),
-                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
-                                        justify_content: JustifyContent::Center,
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::srgb(0.25, 0.25, 0.55)),
-                                    BorderRadius::all(Val::Px(3.0)),
-                                ))
-                                .with_child((
-                                    Text::new("Open Model..."),
-                                    TextFont {
-                                        font_size: 11.0,
-                                        ..default()
-                                    },
-                                    TextColor(Color::WHITE),
-                                ));
-
-                            // Model name label - smaller, with wrapping
-                            content.spawn((
-                                ModelLabel,
-                                Node {
-                                    width: Val::Percent(100.0),
-                                    ..default()
-                                },
-                                Text::new("No model loaded"),
-                                TextFont {
-                                    font_size: 10.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.5, 0.5, 0.5)),
-                                TextLayout::new_with_linebreak(LineBreak::WordBoundary),
-                            ));
-
-                            // Separator
-                            content.spawn((
-                                Node {
-                                    width: Val::Percent(100.0),
-                                    height: Val::Px(1.0),
-                                    margin: UiRect::axes(Val::Px(0.0), Val::Px(2.0)),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 0.5)),
-                            ));
-
-                            // Current animation label (no separate title)
-                            content.spawn((
-                                Text::new("Selected: None"),
-                                AnimationLabel,
-                                TextFont {
-                                    font_size: 10.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.6, 0.6, 0.6)),
-                            ));
-
-                            // Animation list (scrollable)
-                            content
-                                .spawn((
-                                    AnimationScrollArea,
-                                    Interaction::default(),
-                                    RelativeCursorPosition::default(),
-                                    ScrollPosition::default(),
-                                    Node {
-                                        width: Val::Percent(100.0),
-                                        max_height: Val::Px(180.0),
-                                        flex_direction: FlexDirection::Column,
-                                        overflow: Overflow::scroll_y(),
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
-                                    BorderRadius::all(Val::Px(3.0)),
-                                ))
-                                .with_children(|list_container| {
-                                    list_container.spawn((
-                                        AnimationListContainer,
-                                        Node {
-                                            flex_direction: FlexDirection::Column,
-                                            width: Val::Percent(100.0),
-                                            ..default()
-                                        },
-                                    ));
-                                });
-
-                            // Play/Pause button - compact
-                            content
-                                .spawn((
-                                    Button,
-                                    PlayPauseButton,
-                                    Node {
-                                        width: Val::Percent(100.0),
-                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
-                                        justify_content: JustifyContent::Center,
-                                        margin: UiRect::top(Val::Px(4.0)),
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::srgb(0.2, 0.45, 0.2)),
-                                    BorderRadius::all(Val::Px(3.0)),
-                                ))
-                                .with_child((
-                                    Text::new("Play"),
-                                    TextFont {
-                                        font_size: 11.0,
-                                        ..default()
-                                    },
-                                    TextColor(Color::WHITE),
-                                ));
-                        });
-                });
+                .with_child((
+                    Text::new(title),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+
+            // Content area - compact padding
+            content = panel
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .id();
         });
+
+    content
+}
+
+/// Spawn a compact button with a text label, growing by `flex_grow` within its row
+pub fn spawn_small_button(
+    parent: &mut ChildSpawnerCommands,
+    marker: impl Component,
+    label: &str,
+    flex_grow: f32,
+) {
+    parent
+        .spawn((
+            Button,
+            marker,
+            Node {
+                min_width: Val::Px(24.0),
+                flex_grow,
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.18, 0.18, 0.22)),
+            BorderRadius::all(Val::Px(3.0)),
+        ))
+        .with_child((
+            Text::new(label),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        ));
+}
+
+/// Spawn a selectable list row, highlighted when `is_selected`
+pub fn spawn_list_item(
+    parent: &mut ChildSpawnerCommands,
+    marker: impl Component,
+    label: &str,
+    is_selected: bool,
+) {
+    parent
+        .spawn((
+            Button,
+            marker,
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                ..default()
+            },
+            if is_selected {
+                BackgroundColor(Color::srgb(0.2, 0.35, 0.5))
+            } else {
+                BackgroundColor(Color::NONE)
+            },
+        ))
+        .with_child((
+            Text::new(label),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(if is_selected {
+                Color::WHITE
+            } else {
+                Color::srgb(0.75, 0.75, 0.75)
+            }),
+        ));
+}
+
+/// Spawn a slider track starting at `value`, with `marker` on the track entity
+pub fn spawn_slider(parent: &mut ChildSpawnerCommands, marker: impl Component, value: f32) {
+    parent
+        .spawn((
+            marker,
+            Slider { value },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(8.0),
+                margin: UiRect::vertical(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+            BorderRadius::all(Val::Px(2.0)),
+        ))
+        .with_child((
+            SliderFill,
+            Node {
+                width: Val::Percent(value * 100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.5, 0.75)),
+            BorderRadius::all(Val::Px(2.0)),
+        ));
 }
@@ -0,0 +1,290 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy_file_dialog::prelude::*;
+
+use super::interactions::small_button_color;
+use super::layout::{spawn_list_item, spawn_panel, spawn_slider, spawn_small_button};
+use crate::components::*;
+use crate::resources::{LightField, LightRig, RigLight, RigLightKind};
+
+pub fn setup_lighting_panel(mut commands: Commands) {
+    let content = spawn_panel(&mut commands, "Lighting", Vec2::new(990.0, 180.0), 220.0);
+    commands.entity(content).with_children(|content| {
+        content.spawn((
+            LightRigLabel,
+            Text::new("Rig: Default"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, LightRigLoadButton, "Load...", 1.0);
+                spawn_small_button(row, LightRigSaveButton, "Save...", 1.0);
+                spawn_small_button(row, LightRigResetButton, "Reset", 1.0);
+            });
+
+        // Light list (scrollable)
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(80.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                LightListContainer,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+            ));
+
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, AddLightButton(RigLightKind::Directional), "+Dir", 1.0);
+                spawn_small_button(row, AddLightButton(RigLightKind::Point), "+Point", 1.0);
+                spawn_small_button(row, AddLightButton(RigLightKind::Spot), "+Spot", 1.0);
+                spawn_small_button(row, RemoveLightButton, "Del", 0.0);
+            });
+
+        content
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_small_button(row, LightShadowsButton, "Shadows: On", 1.0);
+                spawn_small_button(row, CascadesButton, "Cascades: 4", 1.0);
+            });
+
+        // Sliders start empty and are synced from the rig by `update_lighting_panel`
+        for field in LightField::ALL {
+            content.spawn((
+                LightFieldLabel(field),
+                Text::new(""),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+            spawn_slider(content, LightSlider(field), 0.0);
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn lighting_interactions(
+    mut commands: Commands,
+    mut rig: ResMut<LightRig>,
+    mut buttons: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&AddLightButton>,
+            Has<RemoveLightButton>,
+            Has<LightShadowsButton>,
+            Has<CascadesButton>,
+            Has<LightRigLoadButton>,
+            Has<LightRigSaveButton>,
+            Has<LightRigResetButton>,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(
+                With<AddLightButton>,
+                With<RemoveLightButton>,
+                With<LightShadowsButton>,
+                With<CascadesButton>,
+                With<LightRigLoadButton>,
+                With<LightRigSaveButton>,
+                With<LightRigResetButton>,
+            )>,
+        ),
+    >,
+    sliders: Query<(&Interaction, &Slider, &LightSlider), Changed<Slider>>,
+) {
+    for (
+        interaction,
+        mut bg,
+        add,
+        is_remove,
+        is_shadows,
+        is_cascades,
+        is_load,
+        is_save,
+        is_reset,
+    ) in &mut buttons
+    {
+        *bg = small_button_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(add) = add {
+            rig.lights.push(RigLight::new(add.0));
+            rig.selected = rig.lights.len() - 1;
+        } else if is_remove {
+            if rig.selected < rig.lights.len() {
+                let selected = rig.selected;
+                rig.lights.remove(selected);
+                rig.selected = selected.saturating_sub(1);
+            }
+        } else if is_shadows {
+            if let Some(light) = rig.selected_light_mut() {
+                light.shadows = !light.shadows;
+            }
+        } else if is_cascades {
+            rig.cascades = rig.cascades % 4 + 1;
+        } else if is_load {
+            commands
+                .dialog()
+                .add_filter("Lighting rig", &["rig"])
+                .load_file::<LightRigFile>();
+        } else if is_save {
+            commands
+                .dialog()
+                .add_filter("Lighting rig", &["rig"])
+                .set_file_name(format!("{}.rig", rig.name))
+                .save_file::<LightRigFile>(rig.to_text().into_bytes());
+        } else if is_reset {
+            *rig = LightRig::default();
+        }
+    }
+
+    // Only drags write back, so syncing the sliders from the rig does not loop
+    for (interaction, slider, field) in &sliders {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let mut updated = rig.clone();
+        field.0.set_slider_value(&mut updated, slider.value);
+        rig.set_if_neq(updated);
+    }
+}
+
+pub fn light_list_interactions(
+    mut rig: ResMut<LightRig>,
+    mut items: Query<(&Interaction, &LightListItem, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, item, mut bg) in &mut items {
+        let is_selected = rig.selected == item.0;
+        match *interaction {
+            Interaction::Pressed => rig.selected = item.0,
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                if is_selected {
+                    *bg = BackgroundColor(Color::srgb(0.2, 0.35, 0.5));
+                } else {
+                    *bg = BackgroundColor(Color::NONE);
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_lighting_panel(
+    mut commands: Commands,
+    rig: Res<LightRig>,
+    rig_label: Query<Entity, With<LightRigLabel>>,
+    list_container: Query<Entity, With<LightListContainer>>,
+    existing_items: Query<Entity, With<LightListItem>>,
+    field_labels: Query<(Entity, &LightFieldLabel)>,
+    mut sliders: Query<(&mut Slider, &LightSlider)>,
+    shadows_btn: Query<&Children, With<LightShadowsButton>>,
+    cascades_btn: Query<&Children, With<CascadesButton>>,
+    mut texts: Query<&mut Text>,
+    mut listed: Local<Option<(Vec<RigLightKind>, usize)>>,
+) {
+    if !rig.is_changed() {
+        return;
+    }
+
+    if let Ok(entity) = rig_label.single()
+        && let Ok(mut text) = texts.get_mut(entity)
+    {
+        **text = format!("Rig: {}", rig.name);
+    }
+
+    // Rebuild the light list only when its entries change, not on every slider drag
+    let entries = (
+        rig.lights
+            .iter()
+            .map(|light| light.kind)
+            .collect::<Vec<_>>(),
+        rig.selected,
+    );
+    if listed.as_ref() != Some(&entries)
+        && let Ok(container) = list_container.single()
+    {
+        for entity in &existing_items {
+            commands.entity(entity).despawn();
+        }
+        commands.entity(container).with_children(|parent| {
+            for (i, light) in rig.lights.iter().enumerate() {
+                let label = format!("{}  {}", i + 1, light.kind.label());
+                spawn_list_item(parent, LightListItem(i), &label, i == rig.selected);
+            }
+        });
+        *listed = Some(entries);
+    }
+
+    for (entity, label) in &field_labels {
+        if let Ok(mut text) = texts.get_mut(entity) {
+            **text = label.0.label(&rig);
+        }
+    }
+    for (mut slider, field) in &mut sliders {
+        let value = field.0.slider_value(&rig).unwrap_or(0.0);
+        if (slider.value - value).abs() > 1e-4 {
+            slider.value = value;
+        }
+    }
+
+    let shadows = match rig.selected_light() {
+        Some(light) if light.shadows => "Shadows: On",
+        Some(_) => "Shadows: Off",
+        None => "Shadows: -",
+    };
+    for children in &shadows_btn {
+        set_button_text(children, &mut texts, shadows);
+    }
+    for children in &cascades_btn {
+        set_button_text(children, &mut texts, &format!("Cascades: {}", rig.cascades));
+    }
+}
+
+fn set_button_text(children: &Children, texts: &mut Query<&mut Text>, label: &str) {
+    for child in children.iter() {
+        if let Ok(mut text) = texts.get_mut(child) {
+            **text = label.to_string();
+        }
+    }
+}
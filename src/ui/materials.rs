@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::layout::{spawn_list_item, spawn_panel};
+use crate::components::*;
+use crate::resources::{MaterialBrowser, MaterialVariants};
+
+/// Edge length of texture thumbnails in pixels
+const THUMBNAIL_SIZE: f32 = 36.0;
+
+pub fn setup_material_panel(mut commands: Commands) {
+    let content = spawn_panel(&mut commands, "Materials", Vec2::new(740.0, 360.0), 240.0);
+    commands.entity(content).with_children(|content| {
+        spawn_section_label(content, VariantCountLabel, "Variants");
+        spawn_scroll_list(content, VariantListContainer, 60.0);
+        spawn_section_label(content, MaterialCountLabel, "Materials");
+        spawn_scroll_list(content, MaterialListContainer, 100.0);
+        spawn_section_label(content, TextureCountLabel, "Textures");
+        spawn_scroll_list(content, TextureListContainer, 120.0);
+    });
+}
+
+fn spawn_section_label(parent: &mut ChildSpawnerCommands, marker: impl Component, label: &str) {
+    parent.spawn((
+        marker,
+        Text::new(label),
+        TextFont {
+            font_size: 10.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+    ));
+}
+
+fn spawn_scroll_list(parent: &mut ChildSpawnerCommands, marker: impl Component, max_height: f32) {
+    parent
+        .spawn((
+            ScrollArea,
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            ScrollPosition::default(),
+            Node {
+                width: Val::Percent(100.0),
+                max_height: Val::Px(max_height),
+                flex_direction: FlexDirection::Column,
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+            BorderRadius::all(Val::Px(3.0)),
+        ))
+        .with_child((
+            marker,
+            Node {
+                flex_direction: FlexDirection::Column,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        ));
+}
+
+/// Rebuild the material and texture lists when the browser changes
+#[allow(clippy::type_complexity)]
+pub fn update_material_panel(
+    mut commands: Commands,
+    browser: Res<MaterialBrowser>,
+    material_container: Query<Entity, With<MaterialListContainer>>,
+    texture_container: Query<Entity, With<TextureListContainer>>,
+    existing_items: Query<Entity, Or<(With<MaterialListItem>, With<TextureListItem>)>>,
+    mut material_count: Query<&mut Text, With<MaterialCountLabel>>,
+    mut texture_count: Query<&mut Text, (With<TextureCountLabel>, Without<MaterialCountLabel>)>,
+) {
+    if !browser.is_changed() {
+        return;
+    }
+
+    for entity in &existing_items {
+        commands.entity(entity).despawn();
+    }
+    for mut text in &mut material_count {
+        **text = format!("Materials ({})", browser.materials.len());
+    }
+    for mut text in &mut texture_count {
+        **text = format!("Textures ({})", browser.textures.len());
+    }
+
+    if let Ok(container) = material_container.single() {
+        commands.entity(container).with_children(|parent| {
+            for (i, material) in browser.materials.iter().enumerate() {
+                let label = format!("{}\n{}", material.name, material.summary);
+                spawn_list_item(
+                    parent,
+                    MaterialListItem(i),
+                    &label,
+                    browser.selected == Some(i),
+                );
+            }
+        });
+    }
+
+    if let Ok(container) = texture_container.single() {
+        commands.entity(container).with_children(|parent| {
+            for texture in &browser.textures {
+                let color_space = if texture.is_srgb { "sRGB" } else { "Linear" };
+                let details = format!(
+                    "{}\n{}x{}  {}  {}\nUsed by {}",
+                    texture.label,
+                    texture.width,
+                    texture.height,
+                    texture.format,
+                    color_space,
+                    texture.used_by.join(", ")
+                );
+                parent
+                    .spawn((
+                        TextureListItem,
+                        Node {
+                            width: Val::Percent(100.0),
+                            padding: UiRect::all(Val::Px(4.0)),
+                            column_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            ImageNode::new(texture.handle.clone()),
+                            Node {
+                                width: Val::Px(THUMBNAIL_SIZE),
+                                height: Val::Px(THUMBNAIL_SIZE),
+                                flex_shrink: 0.0,
+                                ..default()
+                            },
+                        ));
+                        row.spawn((
+                            Text::new(details),
+                            TextFont {
+                                font_size: 10.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                            TextLayout::new_with_linebreak(LineBreak::AnyCharacter),
+                            Node {
+                                flex_shrink: 1.0,
+                                ..default()
+                            },
+                        ));
+                    });
+            }
+        });
+    }
+}
+
+/// Clicking a material highlights its meshes, clicking it again clears the highlight
+pub fn material_list_interactions(
+    mut browser: ResMut<MaterialBrowser>,
+    mut items: Query<(&Interaction, &MaterialListItem, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, item, mut bg) in &mut items {
+        let is_selected = browser.selected == Some(item.0);
+        match *interaction {
+            Interaction::Pressed => {
+                browser.selected = if is_selected { None } else { Some(item.0) };
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                if is_selected {
+                    *bg = BackgroundColor(Color::srgb(0.2, 0.35, 0.5));
+                } else {
+                    *bg = BackgroundColor(Color::NONE);
+                }
+            }
+        }
+    }
+}
+
+/// Rebuild the variant list when the variants or the active variant change
+pub fn update_variant_list(
+    mut commands: Commands,
+    variants: Res<MaterialVariants>,
+    container: Query<Entity, With<VariantListContainer>>,
+    existing_items: Query<Entity, With<VariantListItem>>,
+    mut count: Query<&mut Text, With<VariantCountLabel>>,
+) {
+    if !variants.is_changed() {
+        return;
+    }
+
+    for entity in &existing_items {
+        commands.entity(entity).despawn();
+    }
+    for mut text in &mut count {
+        **text = format!("Variants ({})", variants.names.len());
+    }
+
+    let Ok(container) = container.single() else {
+        return;
+    };
+    commands.entity(container).with_children(|parent| {
+        if variants.names.is_empty() {
+            return;
+        }
+        spawn_list_item(
+            parent,
+            VariantListItem(None),
+            "Authored materials",
+            variants.selected.is_none(),
+        );
+        for (i, name) in variants.names.iter().enumerate() {
+            spawn_list_item(
+                parent,
+                VariantListItem(Some(i)),
+                name,
+                variants.selected == Some(i),
+            );
+        }
+    });
+}
+
+/// Clicking a variant swaps the materials of the primitives it maps
+pub fn variant_list_interactions(
+    mut variants: ResMut<MaterialVariants>,
+    mut items: Query<(&Interaction, &VariantListItem, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, item, mut bg) in &mut items {
+        let is_selected = variants.selected == item.0;
+        match *interaction {
+            Interaction::Pressed => {
+                if !is_selected {
+                    variants.selected = item.0;
+                }
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                if is_selected {
+                    *bg = BackgroundColor(Color::srgb(0.2, 0.35, 0.5));
+                } else {
+                    *bg = BackgroundColor(Color::NONE);
+                }
+            }
+        }
+    }
+}
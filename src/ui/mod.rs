@@ -1,7 +1,40 @@
+mod display;
+mod environment;
+mod inspector;
 mod interactions;
 mod layout;
+mod lighting;
+mod materials;
+mod morph;
+mod outliner;
+mod stats;
 mod update;
 
-pub use interactions::{animation_list_interactions, button_interactions};
+pub use display::{
+    display_interactions, measure_interactions, setup_display_panel, update_display_panel,
+    update_measure_buttons,
+};
+pub use environment::{
+    environment_interactions, setup_environment_panel, update_environment_panel,
+};
+pub use inspector::{setup_inspector, update_inspector};
+pub use interactions::{
+    animation_list_interactions, button_interactions, camera_dropdown_interactions,
+    camera_interactions, drag_sliders, playback_interactions, scene_list_interactions,
+    timeline_interactions,
+};
 pub use layout::{setup_scene, setup_ui};
-pub use update::{update_animation_list, update_ui_labels};
+pub use lighting::{
+    light_list_interactions, lighting_interactions, setup_lighting_panel, update_lighting_panel,
+};
+pub use materials::{
+    material_list_interactions, setup_material_panel, update_material_panel, update_variant_list,
+    variant_list_interactions,
+};
+pub use morph::{morph_interactions, setup_morph_panel, update_morph_labels, update_morph_panel};
+pub use outliner::{outliner_interactions, setup_outliner, update_outliner};
+pub use stats::{setup_stats_panel, update_stats_panel};
+pub use update::{
+    update_animation_list, update_camera_list, update_projection_label, update_scene_list,
+    update_slider_fills, update_timeline, update_ui_labels,
+};
@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::interactions::small_button_color;
+use super::layout::{spawn_panel, spawn_slider, spawn_small_button};
+use crate::components::*;
+use crate::resources::MorphTargets;
+
+pub fn setup_morph_panel(mut commands: Commands) {
+    let content = spawn_panel(
+        &mut commands,
+        "Morph Targets",
+        Vec2::new(470.0, 450.0),
+        240.0,
+    );
+    commands.entity(content).with_children(|content| {
+        content.spawn((
+            MorphSummaryLabel,
+            Text::new("No morph targets"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        ));
+
+        spawn_small_button(content, MorphOverrideButton, &override_label(true), 0.0);
+
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(180.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                MorphListContainer,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(4.0)),
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+            ));
+    });
+}
+
+fn override_label(animation_override: bool) -> String {
+    if animation_override {
+        "Animation: Overrides sliders".to_string()
+    } else {
+        "Animation: Ignored".to_string()
+    }
+}
+
+fn weight_label(name: &str, weight: f32) -> String {
+    format!("{name}  {weight:.2}")
+}
+
+/// Rebuild the slider list when the model's morph targets change
+#[allow(clippy::too_many_arguments)]
+pub fn update_morph_panel(
+    mut commands: Commands,
+    morph: Res<MorphTargets>,
+    weights_query: Query<&MorphWeights>,
+    container: Query<Entity, With<MorphListContainer>>,
+    existing_items: Query<Entity, With<MorphListItem>>,
+    mut summary: Query<&mut Text, With<MorphSummaryLabel>>,
+    override_btn: Query<&Children, With<MorphOverrideButton>>,
+    mut button_text: Query<&mut Text, Without<MorphSummaryLabel>>,
+) {
+    if !morph.is_changed() {
+        return;
+    }
+
+    let target_count: usize = morph.meshes.iter().map(|mesh| mesh.targets.len()).sum();
+    for mut text in &mut summary {
+        **text = if target_count == 0 {
+            "No morph targets".to_string()
+        } else {
+            format!("{} targets on {} meshes", target_count, morph.meshes.len())
+        };
+    }
+    for children in &override_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = override_label(morph.animation_override);
+            }
+        }
+    }
+
+    for entity in &existing_items {
+        commands.entity(entity).despawn();
+    }
+    let Ok(container) = container.single() else {
+        return;
+    };
+    commands.entity(container).with_children(|parent| {
+        for mesh in &morph.meshes {
+            let weights = weights_query
+                .get(mesh.entity)
+                .map(|weights| weights.weights())
+                .unwrap_or_default();
+            parent.spawn((
+                MorphListItem,
+                Text::new(&mesh.name),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            for (index, target) in mesh.targets.iter().enumerate() {
+                let weight = weights.get(index).copied().unwrap_or(0.0);
+                parent
+                    .spawn((
+                        MorphListItem,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(2.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            MorphTargetLabel(target.clone()),
+                            Text::new(weight_label(target, weight)),
+                            TextFont {
+                                font_size: 10.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                        ));
+                        spawn_slider(
+                            row,
+                            MorphTargetSlider {
+                                entity: mesh.entity,
+                                index,
+                                dragged: false,
+                            },
+                            weight.clamp(0.0, 1.0),
+                        );
+                    });
+            }
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn morph_interactions(
+    mut morph: ResMut<MorphTargets>,
+    mut buttons: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<MorphOverrideButton>),
+    >,
+    mut sliders: Query<(&Interaction, &Slider, &mut MorphTargetSlider), Changed<Slider>>,
+    mut weights_query: Query<&mut MorphWeights>,
+) {
+    for (interaction, mut bg) in &mut buttons {
+        *bg = small_button_color(*interaction);
+        if *interaction == Interaction::Pressed {
+            morph.animation_override = !morph.animation_override;
+        }
+    }
+
+    // Only drags write back, so following the animation does not loop. The weight is set right
+    // away so dragging works while no clip animates it, even when animations override the sliders.
+    for (interaction, slider, mut target) in &mut sliders {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        target.dragged = true;
+        let Ok(mut weights) = weights_query.get_mut(target.entity) else {
+            continue;
+        };
+        if let Some(weight) = weights.weights_mut().get_mut(target.index) {
+            *weight = slider.value;
+        }
+    }
+}
+
+/// Show the current weight next to each morph target name
+#[allow(clippy::type_complexity)]
+pub fn update_morph_labels(
+    sliders: Query<(&Slider, &ChildOf), (Changed<Slider>, With<MorphTargetSlider>)>,
+    rows: Query<&Children>,
+    mut labels: Query<(&MorphTargetLabel, &mut Text)>,
+) {
+    for (slider, child_of) in &sliders {
+        let Ok(siblings) = rows.get(child_of.parent()) else {
+            continue;
+        };
+        for sibling in siblings.iter() {
+            if let Ok((label, mut text)) = labels.get_mut(sibling) {
+                **text = weight_label(&label.0, slider.value);
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use bevy::mesh::skinning::SkinnedMesh;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::interactions::small_button_color;
+use super::layout::spawn_panel;
+use crate::components::*;
+use crate::resources::{ModelViewer, OutlinerState, Selection};
+
+/// Scene node components shown in an outliner row
+type NodeInfo<'a> = (
+    Option<&'a Name>,
+    Option<&'a Visibility>,
+    Has<Mesh3d>,
+    Has<SkinnedMesh>,
+    Has<PointLight>,
+    Has<SpotLight>,
+    Has<DirectionalLight>,
+    Has<Camera>,
+);
+
+pub fn setup_outliner(mut commands: Commands) {
+    let content = spawn_panel(&mut commands, "Outliner", Vec2::new(220.0, 10.0), 240.0);
+    commands.entity(content).with_children(|content| {
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(360.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.9)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_child((
+                OutlinerContainer,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    padding: UiRect::vertical(Val::Px(2.0)),
+                    ..default()
+                },
+            ));
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_outliner(
+    mut commands: Commands,
+    viewer: Res<ModelViewer>,
+    mut outliner: ResMut<OutlinerState>,
+    selection: Res<Selection>,
+    container: Query<Entity, With<OutlinerContainer>>,
+    rows: Query<Entity, With<OutlinerRow>>,
+    children_query: Query<&Children>,
+    nodes: Query<NodeInfo>,
+) {
+    let Ok(container) = container.single() else {
+        return;
+    };
+
+    // The scene spawns asynchronously, so rebuild whenever its node count changes
+    let built_for = viewer
+        .current_model
+        .map(|root| (root, children_query.iter_descendants(root).count()));
+    if built_for == outliner.built_for && !outliner.is_changed() && !selection.is_changed() {
+        return;
+    }
+    outliner.bypass_change_detection().built_for = built_for;
+
+    // Clear existing rows
+    for entity in &rows {
+        commands.entity(entity).despawn();
+    }
+
+    let Some((root, _)) = built_for else {
+        return;
+    };
+
+    // Depth-first walk that skips the children of collapsed nodes
+    let mut visible_nodes = Vec::new();
+    let mut to_visit: Vec<(Entity, usize)> = children_query
+        .get(root)
+        .map(|children| children.iter().rev().map(|child| (child, 0)).collect())
+        .unwrap_or_default();
+    while let Some((entity, depth)) = to_visit.pop() {
+        visible_nodes.push((entity, depth));
+        if outliner.collapsed.contains(&entity) {
+            continue;
+        }
+        if let Ok(children) = children_query.get(entity) {
+            to_visit.extend(children.iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+
+    commands.entity(container).with_children(|parent| {
+        for (entity, depth) in visible_nodes {
+            let Ok((name, visibility, mesh, skin, point, spot, directional, camera)) =
+                nodes.get(entity)
+            else {
+                continue;
+            };
+            let name = name.map_or_else(|| format!("Entity {}", entity.index()), Name::to_string);
+            let hidden = visibility == Some(&Visibility::Hidden);
+            let badges: Vec<&str> = [
+                (mesh, "M"),
+                (skin, "S"),
+                (point || spot || directional, "L"),
+                (camera, "C"),
+            ]
+            .into_iter()
+            .filter_map(|(present, badge)| present.then_some(badge))
+            .collect();
+
+            let expandable = children_query.get(entity).is_ok();
+            let collapsed = outliner.collapsed.contains(&entity);
+            spawn_outliner_row(
+                parent,
+                RowInfo {
+                    entity,
+                    depth,
+                    collapsed: expandable.then_some(collapsed),
+                    selected: selection.entity == Some(entity),
+                    hidden,
+                    name,
+                    badges: badges.join(" "),
+                },
+            );
+        }
+    });
+}
+
+/// Everything shown in one outliner row
+struct RowInfo {
+    entity: Entity,
+    depth: usize,
+    /// `None` for nodes without children
+    collapsed: Option<bool>,
+    selected: bool,
+    hidden: bool,
+    name: String,
+    badges: String,
+}
+
+fn spawn_outliner_row(parent: &mut ChildSpawnerCommands, info: RowInfo) {
+    let RowInfo {
+        entity,
+        depth,
+        collapsed,
+        selected,
+        hidden,
+        name,
+        badges,
+    } = info;
+
+    parent
+        .spawn((
+            Button,
+            OutlinerRow(entity),
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect {
+                    left: Val::Px(4.0 + depth as f32 * 10.0),
+                    right: Val::Px(4.0),
+                    top: Val::Px(1.0),
+                    bottom: Val::Px(1.0),
+                },
+                column_gap: Val::Px(4.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            if selected {
+                BackgroundColor(Color::srgb(0.2, 0.35, 0.5))
+            } else {
+                BackgroundColor(Color::NONE)
+            },
+        ))
+        .with_children(|row| {
+            // Expand/collapse toggle, or a spacer for leaf nodes
+            let mut toggle = row.spawn(Node {
+                width: Val::Px(12.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            });
+            if let Some(collapsed) = collapsed {
+                toggle
+                    .insert((Button, OutlinerExpandButton(entity)))
+                    .with_child((
+                        Text::new(if collapsed { "+" } else { "-" }),
+                        TextFont {
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                    ));
+            }
+
+            row.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                Text::new(name),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(if hidden {
+                    Color::srgb(0.4, 0.4, 0.4)
+                } else {
+                    Color::srgb(0.8, 0.8, 0.8)
+                }),
+                TextLayout::new_with_no_wrap(),
+            ));
+
+            if !badges.is_empty() {
+                row.spawn((
+                    Text::new(badges),
+                    TextFont {
+                        font_size: 9.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.45, 0.65, 0.85)),
+                ));
+            }
+
+            row.spawn((
+                Button,
+                VisibilityToggle(entity),
+                Node {
+                    min_width: Val::Px(26.0),
+                    padding: UiRect::axes(Val::Px(4.0), Val::Px(1.0)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                small_button_color(Interaction::None),
+                BorderRadius::all(Val::Px(2.0)),
+            ))
+            .with_child((
+                Text::new(if hidden { "off" } else { "on" }),
+                TextFont {
+                    font_size: 9.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn outliner_interactions(
+    mut outliner: ResMut<OutlinerState>,
+    mut selection: ResMut<Selection>,
+    mut rows: Query<(&Interaction, &OutlinerRow, &mut BackgroundColor), Changed<Interaction>>,
+    expand_btn: Query<(&Interaction, &OutlinerExpandButton), Changed<Interaction>>,
+    mut visibility_btn: Query<
+        (&Interaction, &VisibilityToggle, &mut BackgroundColor),
+        (Changed<Interaction>, Without<OutlinerRow>),
+    >,
+    mut visibilities: Query<&mut Visibility>,
+) {
+    // Clicking a row selects its node; the rebuild restores the row colors
+    for (interaction, row, mut bg) in &mut rows {
+        match *interaction {
+            Interaction::Pressed => {
+                selection.entity = Some(row.0);
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(Color::srgb(0.3, 0.3, 0.5));
+            }
+            Interaction::None => {
+                *bg = if selection.entity == Some(row.0) {
+                    BackgroundColor(Color::srgb(0.2, 0.35, 0.5))
+                } else {
+                    BackgroundColor(Color::NONE)
+                };
+            }
+        }
+    }
+
+    for (interaction, button) in &expand_btn {
+        if *interaction == Interaction::Pressed && !outliner.collapsed.remove(&button.0) {
+            outliner.collapsed.insert(button.0);
+        }
+    }
+
+    for (interaction, toggle, mut bg) in &mut visibility_btn {
+        *bg = small_button_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut visibility) = visibilities.get_mut(toggle.0) {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+            // Rebuild rows so the toggle label follows the new state
+            outliner.set_changed();
+        }
+    }
+}
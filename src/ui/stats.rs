@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use std::fmt::Write;
+
+use super::layout::spawn_panel;
+use crate::components::*;
+use crate::resources::ModelStats;
+
+pub fn setup_stats_panel(mut commands: Commands) {
+    let content = spawn_panel(&mut commands, "Statistics", Vec2::new(740.0, 10.0), 240.0);
+    commands.entity(content).with_children(|content| {
+        content
+            .spawn((
+                ScrollArea,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                ScrollPosition::default(),
+                Node {
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(300.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+            ))
+            .with_child((
+                StatsText,
+                Text::new("No model loaded"),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.75)),
+            ));
+    });
+}
+
+pub fn update_stats_panel(
+    stats: Res<ModelStats>,
+    mut stats_text: Query<&mut Text, With<StatsText>>,
+) {
+    if !stats.is_changed() {
+        return;
+    }
+    let Ok(mut text) = stats_text.single_mut() else {
+        return;
+    };
+    if stats.source.is_none() {
+        **text = "No model loaded".to_string();
+        return;
+    }
+
+    // Writing to a String cannot fail, so the results are ignored
+    let mut out = String::new();
+    let _ = writeln!(out, "Meshes       {}", stats.meshes);
+    let _ = writeln!(out, "Primitives   {}", stats.primitives);
+    let _ = writeln!(out, "Vertices     {}", stats.vertices);
+    let _ = writeln!(out, "Triangles    {}", stats.triangles);
+    let _ = writeln!(out, "Materials    {}", stats.materials);
+    let _ = writeln!(
+        out,
+        "Skins        {} ({} joints)",
+        stats.skins, stats.joints
+    );
+    let _ = write!(out, "Animations   {}", stats.animations);
+    match stats.keyframes {
+        Some(keyframes) => {
+            let _ = writeln!(out, " ({keyframes} keyframes)");
+        }
+        None => {
+            let _ = writeln!(out);
+        }
+    }
+    let _ = writeln!(
+        out,
+        "Textures     {} ({})",
+        stats.textures.len(),
+        format_bytes(stats.texture_bytes())
+    );
+    for texture in &stats.textures {
+        let _ = writeln!(
+            out,
+            "  {}  {}x{}  {}  {}",
+            texture.label,
+            texture.width,
+            texture.height,
+            texture.format,
+            format_bytes(texture.gpu_bytes)
+        );
+    }
+
+    **text = out.trim_end().to_string();
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const KIB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= KIB * KIB {
+        format!("{:.1} MB", bytes / (KIB * KIB))
+    } else {
+        format!("{:.1} KB", bytes / KIB)
+    }
+}
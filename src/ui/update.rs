@@ -1,7 +1,10 @@
 use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
 
+use super::layout::spawn_list_item;
 use crate::components::*;
-use crate::resources::ModelViewer;
+use crate::resources::{ActiveCamera, ModelViewer};
+use crate::systems::scene_cameras;
 
 pub fn update_animation_list(
     mut commands: Commands,
@@ -28,7 +31,7 @@ pub fn update_animation_list(
 
     // Add new items
     commands.entity(container).with_children(|parent| {
-        if viewer.animation_names.is_empty() {
+        if viewer.animations.is_empty() {
             parent.spawn((
                 NoAnimationsText,
                 Text::new("No animations"),
@@ -43,46 +46,119 @@ pub fn update_animation_list(
                 },
             ));
         } else {
-            for (i, name) in viewer.animation_names.iter().enumerate() {
+            for (i, entry) in viewer.animations.iter().enumerate() {
                 let is_selected = i == viewer.current_animation;
-                parent
-                    .spawn((
-                        Button,
-                        AnimationListItem(i),
-                        Node {
-                            width: Val::Percent(100.0),
-                            padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
-                            ..default()
-                        },
-                        if is_selected {
-                            BackgroundColor(Color::srgb(0.2, 0.35, 0.5))
-                        } else {
-                            BackgroundColor(Color::NONE)
-                        },
-                    ))
-                    .with_child((
-                        Text::new(name.clone()),
-                        TextFont {
-                            font_size: 10.0,
-                            ..default()
-                        },
-                        TextColor(if is_selected {
-                            Color::WHITE
-                        } else {
-                            Color::srgb(0.75, 0.75, 0.75)
-                        }),
-                    ));
+                spawn_list_item(parent, AnimationListItem(i), &entry.name, is_selected);
             }
         }
     });
 }
 
+pub fn update_scene_list(
+    mut commands: Commands,
+    viewer: Res<ModelViewer>,
+    list_container: Query<Entity, With<SceneListContainer>>,
+    existing_items: Query<Entity, With<SceneListItem>>,
+) {
+    if !viewer.is_changed() {
+        return;
+    }
+
+    let Ok(container) = list_container.single() else {
+        return;
+    };
+
+    for entity in &existing_items {
+        commands.entity(entity).despawn();
+    }
+
+    commands.entity(container).with_children(|parent| {
+        for (i, name) in viewer.scenes.iter().enumerate() {
+            let is_selected = i == viewer.current_scene;
+            spawn_list_item(parent, SceneListItem(i), name, is_selected);
+        }
+    });
+}
+
+/// Rebuild the camera dropdown when scene cameras come and go or the active one changes
+#[allow(clippy::too_many_arguments)]
+pub fn update_camera_list(
+    mut commands: Commands,
+    active: Res<ActiveCamera>,
+    scene_camera_query: Query<Entity, (With<Camera3d>, Without<PanOrbitCamera>)>,
+    names: Query<&Name>,
+    list_container: Query<Entity, With<CameraDropdownList>>,
+    existing_items: Query<Entity, With<CameraListItem>>,
+    dropdown_btn: Query<&Children, With<CameraDropdownButton>>,
+    mut button_text: Query<&mut Text>,
+    mut listed: Local<Vec<Entity>>,
+) {
+    let cameras = scene_cameras(&scene_camera_query);
+    if *listed == cameras && !active.is_changed() {
+        return;
+    }
+    *listed = cameras;
+
+    let Ok(container) = list_container.single() else {
+        return;
+    };
+
+    for entity in &existing_items {
+        commands.entity(entity).despawn();
+    }
+
+    let camera_name = |index: usize, entity: Entity| {
+        names
+            .get(entity)
+            .map_or_else(|_| format!("Camera {}", index + 1), Name::to_string)
+    };
+
+    commands.entity(container).with_children(|parent| {
+        spawn_list_item(
+            parent,
+            CameraListItem(None),
+            "Orbit camera",
+            active.scene_camera.is_none(),
+        );
+        for (i, entity) in listed.iter().enumerate() {
+            spawn_list_item(
+                parent,
+                CameraListItem(Some(*entity)),
+                &camera_name(i, *entity),
+                active.scene_camera == Some(*entity),
+            );
+        }
+    });
+
+    let current = match active.scene_camera {
+        Some(entity) => {
+            let index = listed.iter().position(|e| *e == entity).unwrap_or(0);
+            camera_name(index, entity)
+        }
+        None => "Orbit camera".to_string(),
+    };
+    for children in &dropdown_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = format!("View: {current}");
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_ui_labels(
     viewer: Res<ModelViewer>,
     mut model_label: Query<&mut Text, (With<ModelLabel>, Without<AnimationLabel>)>,
     mut anim_label: Query<&mut Text, (With<AnimationLabel>, Without<ModelLabel>)>,
     mut play_btn: Query<(&Children, &mut BackgroundColor), With<PlayPauseButton>>,
-    mut play_btn_text: Query<&mut Text, (Without<ModelLabel>, Without<AnimationLabel>)>,
+    mut button_text: Query<&mut Text, (Without<ModelLabel>, Without<AnimationLabel>)>,
+    sort_btn: Query<&Children, With<SortOrderButton>>,
+    fps_btn: Query<&Children, With<FrameRateButton>>,
+    speed_label: Query<Entity, With<SpeedLabel>>,
+    reverse_btn: Query<&Children, With<ReverseButton>>,
+    loop_btn: Query<&Children, With<LoopModeButton>>,
+    crossfade_btn: Query<&Children, With<CrossfadeButton>>,
 ) {
     if !viewer.is_changed() {
         return;
@@ -100,17 +176,17 @@ pub fn update_ui_labels(
 
     // Update animation label
     for mut text in &mut anim_label {
-        if viewer.animation_names.is_empty() {
+        if let Some(entry) = viewer.current_entry() {
+            **text = format!("Selected: {}", entry.name);
+        } else {
             **text = "Selected: None".to_string();
-        } else if let Some(name) = viewer.animation_names.get(viewer.current_animation) {
-            **text = format!("Selected: {}", name);
         }
     }
 
     // Update play/pause button
     for (children, mut bg) in &mut play_btn {
         for child in children.iter() {
-            if let Ok(mut text) = play_btn_text.get_mut(child) {
+            if let Ok(mut text) = button_text.get_mut(child) {
                 if viewer.is_playing {
                     **text = "Pause".to_string();
                     *bg = BackgroundColor(Color::srgb(0.5, 0.2, 0.2));
@@ -121,4 +197,126 @@ pub fn update_ui_labels(
             }
         }
     }
+
+    // Update sort order button
+    for children in &sort_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = format!("Sort: {}", viewer.sort_order.label());
+            }
+        }
+    }
+
+    // Update frame rate button
+    for children in &fps_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = format!("{} fps", viewer.frame_rate.fps());
+            }
+        }
+    }
+
+    // Update playback speed, direction and loop mode
+    for entity in &speed_label {
+        if let Ok(mut text) = button_text.get_mut(entity) {
+            **text = format!("{:.2}x", viewer.speed);
+        }
+    }
+    for children in &reverse_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = if viewer.reverse { "Reverse" } else { "Forward" }.to_string();
+            }
+        }
+    }
+    for children in &loop_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = viewer.loop_mode.label().to_string();
+            }
+        }
+    }
+    for children in &crossfade_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = if viewer.crossfade > 0.0 {
+                    format!("Fade: {}s", viewer.crossfade)
+                } else {
+                    "Fade: Off".to_string()
+                };
+            }
+        }
+    }
+}
+
+pub fn update_timeline(
+    viewer: Res<ModelViewer>,
+    players: Query<&AnimationPlayer>,
+    mut fill: Query<&mut Node, With<TimelineFill>>,
+    mut label: Query<&mut Text, With<TimelineLabel>>,
+) {
+    let (time, duration) = viewer
+        .current_entry()
+        .map(|entry| {
+            let time = players
+                .iter()
+                .find_map(|player| player.animation(entry.node))
+                .map_or(0.0, |active| active.seek_time());
+            (time, entry.duration)
+        })
+        .unwrap_or_default();
+
+    let progress = if duration > 0.0 {
+        (time / duration).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    for mut node in &mut fill {
+        node.width = Val::Percent(progress * 100.0);
+    }
+
+    let frame_rate = viewer.frame_rate;
+    for mut text in &mut label {
+        **text = format!(
+            "{:.2} / {:.2} s   frame {} / {}",
+            time,
+            duration,
+            frame_rate.frame_at(time),
+            frame_rate.frame_at(duration)
+        );
+    }
+}
+
+pub fn update_projection_label(
+    camera_query: Query<&Projection, (With<PanOrbitCamera>, Changed<Projection>)>,
+    projection_btn: Query<&Children, With<ProjectionButton>>,
+    mut button_text: Query<&mut Text>,
+) {
+    let Some(projection) = camera_query.iter().next() else {
+        return;
+    };
+    let label = match projection {
+        Projection::Orthographic(_) => "Ortho",
+        _ => "Persp",
+    };
+    for children in &projection_btn {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                **text = label.to_string();
+            }
+        }
+    }
+}
+
+pub fn update_slider_fills(
+    sliders: Query<(&Slider, &Children), Changed<Slider>>,
+    mut fills: Query<&mut Node, With<SliderFill>>,
+) {
+    for (slider, children) in &sliders {
+        for child in children.iter() {
+            if let Ok(mut node) = fills.get_mut(child) {
+                node.width = Val::Percent(slider.value * 100.0);
+            }
+        }
+    }
 }